@@ -0,0 +1,53 @@
+//! Compares request throughput of a shared, pooled client against building a fresh client (and
+//! connection) for every request. Runs against a minimal local JSON-RPC server.
+//!
+//! Run with `cargo bench --bench pooling`.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use std::time::{Duration, Instant};
+
+use nimiq_rpc::{Client, ClientBuilder};
+use serde_json::json;
+use url::Url;
+
+const REQUESTS: u32 = 2000;
+
+async fn pooled(url: &Url) -> Duration {
+    let client = ClientBuilder::new()
+        .max_concurrent_requests(8)
+        .build(url.clone())
+        .unwrap();
+    let start = Instant::now();
+    for _ in 0..REQUESTS {
+        client.block_number().await.unwrap();
+    }
+    start.elapsed()
+}
+
+async fn unpooled(url: &Url) -> Duration {
+    let start = Instant::now();
+    for _ in 0..REQUESTS {
+        Client::new(url.clone()).block_number().await.unwrap();
+    }
+    start.elapsed()
+}
+
+#[tokio::main]
+async fn main() {
+    let url = common::mock_node(|_, _| Ok(json!(1))).await;
+
+    for (name, elapsed) in [
+        ("pooled", pooled(&url).await),
+        ("unpooled", unpooled(&url).await),
+    ] {
+        println!(
+            "{:>8}: {} requests in {:?} ({:.0} req/s)",
+            name,
+            REQUESTS,
+            elapsed,
+            REQUESTS as f64 / elapsed.as_secs_f64()
+        );
+    }
+}
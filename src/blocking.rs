@@ -0,0 +1,148 @@
+//! A synchronous facade over [`Client`] for programs that do not run an async runtime.
+
+use std::future::Future;
+
+use url::Url;
+
+use crate::error::NimiqError as Error;
+use crate::primitives::*;
+use crate::Client;
+
+/// A client whose methods block until the node answers.
+///
+/// Requests run on a single-threaded `tokio` runtime owned by the client. The methods must not be called
+/// from within an async runtime, which panics; use [`Client`] there instead. Methods without a blocking
+/// counterpart can be called with [`BlockingClient::block_on`].
+#[derive(Debug)]
+pub struct BlockingClient {
+    client: Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl BlockingClient {
+    pub fn new(url: Url) -> BlockingClient {
+        BlockingClient::from_client(Client::new(url))
+    }
+
+    /// Wraps a client, e.g. one configured with [`ClientBuilder`](crate::ClientBuilder).
+    ///
+    /// Panics if the runtime cannot be created.
+    pub fn from_client(client: Client) -> BlockingClient {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create runtime");
+        BlockingClient { client, runtime }
+    }
+
+    /// Returns the wrapped async client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Runs a future on the runtime of this client until it completes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::blocking::BlockingClient;
+    /// use url::Url;
+    /// let client = BlockingClient::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.block_on(client.client().hashrate());
+    /// ```
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Returns the height of the most recent block, see [`Client::block_number`].
+    pub fn block_number(&self) -> Result<u32, Error> {
+        self.block_on(self.client.block_number())
+    }
+
+    /// Returns the consensus state of the node, see [`Client::consensus`].
+    pub fn consensus(&self) -> Result<String, Error> {
+        self.block_on(self.client.consensus())
+    }
+
+    /// Returns the number of connected peers, see [`Client::peer_count`].
+    pub fn peer_count(&self) -> Result<i8, Error> {
+        self.block_on(self.client.peer_count())
+    }
+
+    /// Returns the sync status of the node, see [`Client::syncing`].
+    pub fn syncing(&self) -> Result<Syncing, Error> {
+        self.block_on(self.client.syncing())
+    }
+
+    /// Returns the block height, peer count, consensus state, sync status and hashrate of the node, see
+    /// [`Client::node_status`].
+    pub fn node_status(&self) -> Result<NodeStatus, Error> {
+        self.block_on(self.client.node_status())
+    }
+
+    /// Returns details for the account of given address, see [`Client::get_account`].
+    pub fn get_account(&self, id: &str) -> Result<Account, Error> {
+        self.block_on(self.client.get_account(id))
+    }
+
+    /// Returns the balance of an address, see [`Client::get_balance`].
+    pub fn get_balance(&self, id: &str) -> Result<u64, Error> {
+        self.block_on(self.client.get_balance(id))
+    }
+
+    /// Returns information about a block by hash, see [`Client::get_block_by_hash`].
+    pub fn get_block_by_hash(
+        &self,
+        block_hash: &str,
+        full_transactions: bool,
+    ) -> Result<Block, Error> {
+        self.block_on(self.client.get_block_by_hash(block_hash, full_transactions))
+    }
+
+    /// Returns information about a block by block number, see [`Client::get_block_by_number`].
+    pub fn get_block_by_number(
+        &self,
+        block_number: u32,
+        full_transactions: bool,
+    ) -> Result<Block, Error> {
+        self.block_on(
+            self.client
+                .get_block_by_number(block_number, full_transactions),
+        )
+    }
+
+    /// Returns information about a transaction, see [`Client::get_transaction_by_hash`].
+    pub fn get_transaction_by_hash(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionDetails, Error> {
+        self.block_on(self.client.get_transaction_by_hash(transaction_hash))
+    }
+
+    /// Returns the receipt of a transaction, see [`Client::get_transaction_receipt`].
+    pub fn get_transaction_receipt(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionReceipt, Error> {
+        self.block_on(self.client.get_transaction_receipt(transaction_hash))
+    }
+
+    /// Returns the latest transactions of an address, see [`Client::get_transactions_by_address`].
+    pub fn get_transactions_by_address(
+        &self,
+        address: &str,
+        amount: u16,
+    ) -> Result<Vec<TransactionDetails>, Error> {
+        self.block_on(self.client.get_transactions_by_address(address, amount))
+    }
+
+    /// Sends a signed transaction, see [`Client::send_raw_transaction`].
+    pub fn send_raw_transaction(&self, transaction: &str) -> Result<String, Error> {
+        self.block_on(self.client.send_raw_transaction(transaction))
+    }
+
+    /// Sends a transaction signed by the node, see [`Client::send_transaction`].
+    pub fn send_transaction(&self, transaction: &OutgoingTransaction) -> Result<String, Error> {
+        self.block_on(self.client.send_transaction(transaction))
+    }
+}
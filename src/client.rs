@@ -1,53 +1,2292 @@
 use base64::Engine;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
 use jsonrpsee::{
-    core::{client::ClientT, ClientError as Error},
-    http_client::{HeaderMap, HttpClient, HttpClientBuilder},
+    core::{
+        client::{BatchResponse, ClientT, IdKind},
+        params::BatchRequestBuilder,
+        traits::ToRpcParams,
+        ClientError,
+    },
+    http_client::{transport::HttpBackend, HeaderMap, HttpClient, HttpClientBuilder},
     rpc_params,
 };
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tower::ServiceBuilder;
+use tower_http::decompression::{Decompression, DecompressionLayer};
 
 use url::Url;
 
-use crate::primitives::*;
+use crate::error::NimiqError as Error;
+use crate::metrics::Metrics;
+use crate::mining;
+use crate::primitives::{de_u64_flexible, *};
+
+/// Methods whose parameters contain passphrases or key material and therefore are never logged.
+#[cfg(feature = "tracing")]
+const SENSITIVE_METHODS: &[&str] = &["createAccount", "importRawKey", "sign", "unlockAccount"];
+
+/// How long `Client::create_and_send` keeps signed transactions for retries: the validity window at
+/// the target block time of one minute, after which the transactions cannot be included anymore.
+const SIGNED_TRANSACTION_LIFETIME: Duration =
+    Duration::from_secs(TRANSACTION_VALIDITY_WINDOW as u64 * 60);
+
+/// How many blocks `Client::get_block_range` requests at the same time.
+const BLOCK_RANGE_CONCURRENCY: usize = 8;
 
+/// How often `Client::confirm` checks for a transaction receipt.
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often `Client::send_and_verify_propagation` checks the mempool for a sent transaction.
+const PROPAGATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// An HTTP client for the Nimiq JSON-RPC API.
+///
+/// Every method returns a future that is safe to cancel: dropping it before completion aborts the
+/// in-flight request and closes its connection, so calls can be bounded individually with
+/// `tokio::time::timeout` or raced in `tokio::select!`. Note that a dropped request may still have
+/// reached the node, so state-changing calls such as `send_transaction` may or may not have taken effect.
+///
+/// Methods taking a block or transaction hash accept it in upper or lower case, with or without `0x`
+/// prefix. Hashes returned by the node are always lowercase hex without prefix, so they can be compared
+/// with `==`.
+///
+/// The client runs no background tasks, so no shutdown call is needed: its pooled connections are closed
+/// when the last clone is dropped. As every call borrows the client, pending calls have to finish or be
+/// dropped first, so awaiting or dropping them before dropping the client shuts it down cleanly.
 #[derive(Clone)]
 pub struct Client {
-    agent: HttpClient,
+    agent: HttpClient<Decompression<HttpBackend>>,
+    url: Url,
+    /// Settings the client was built with, to build clients for `call_with_headers`.
+    builder: ClientBuilder,
+    account_cache: Option<Arc<Mutex<AccountCache>>>,
+    block_cache: Option<Arc<Mutex<BlockCache>>>,
+    history_cache: Option<Arc<Mutex<HistoryCache>>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Number of the genesis block, see `with_genesis_block_number`.
+    genesis_block_number: u32,
+    /// Transactions signed by `create_and_send`, by idempotency key, with when the key was first used.
+    signed_transactions: Arc<Mutex<HashMap<String, (SignedSlot, Instant)>>>,
+    on_request: Option<Arc<RequestHook>>,
+    on_response: Option<Arc<ResponseHook>>,
+    metrics: Option<Arc<Metrics>>,
+}
+
+/// The transaction signed for an idempotency key of `Client::create_and_send`, if any. Locked while
+/// signing, so that concurrent calls with the same key do not sign twice.
+type SignedSlot = Arc<tokio::sync::Mutex<Option<(OutgoingTransaction, String)>>>;
+
+/// Called with the method name before every request, see `Client::with_interceptor`.
+type RequestHook = dyn Fn(&str) + Send + Sync;
+
+/// Called with the method name, the elapsed time and the outcome after every request, see
+/// `Client::with_response_hook`.
+type ResponseHook = dyn Fn(&str, Duration, Result<(), &Error>) + Send + Sync;
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The underlying `HttpClient` would print the `Authorization` header, so only show the URL.
+        let mut url = self.url.clone();
+        if url.password().is_some() {
+            let _ = url.set_password(Some("redacted"));
+        }
+        f.debug_struct("Client")
+            .field("url", &url.as_str())
+            .field("account_cache", &self.account_cache.is_some())
+            .field(
+                "block_cache",
+                &self
+                    .block_cache
+                    .as_ref()
+                    .map(|cache| cache.lock().unwrap().confirmations),
+            )
+            .field(
+                "history_cache",
+                &self
+                    .history_cache
+                    .as_ref()
+                    .map(|cache| cache.lock().unwrap().ttl),
+            )
+            .field(
+                "rate_limit",
+                &self.rate_limiter.as_ref().map(|limiter| limiter.interval),
+            )
+            .field("interceptor", &self.on_request.is_some())
+            .field("response_hook", &self.on_response.is_some())
+            .field("metrics", &self.metrics.is_some())
+            .finish()
+    }
+}
+
+/// Accounts returned by `get_account`, valid as long as the chain stays at `block_number`.
+#[derive(Default)]
+struct AccountCache {
+    block_number: Option<u32>,
+    accounts: HashMap<String, Account>,
+}
+
+impl AccountCache {
+    /// Returns the key of an account, with the address in hex so that all address formats share it.
+    fn key(address: &str) -> String {
+        address_bytes(address)
+            .map(hex::encode)
+            .unwrap_or_else(|| address.to_string())
+    }
+
+    /// Stores an account fetched while the chain was at `block_number`. The account is dropped if the
+    /// height changed since, as it may then hold the balance of an older block.
+    fn insert(&mut self, key: String, account: Account, block_number: Option<u32>) {
+        if self.block_number == block_number {
+            self.accounts.insert(key, account);
+        }
+    }
+
+    /// Records the current block height, dropping all accounts if it changed.
+    fn observe_block_number(&mut self, block_number: u32) {
+        if self.block_number != Some(block_number) {
+            self.accounts.clear();
+            self.block_number = Some(block_number);
+        }
+    }
+}
+
+/// Results of `get_transactions_by_address` by address and amount, each kept for `ttl` after it was fetched.
+struct HistoryCache {
+    ttl: Duration,
+    entries: HashMap<(String, u16), (Vec<TransactionDetails>, Instant)>,
+}
+
+impl HistoryCache {
+    /// Returns the key of a history, with the address in hex so that all address formats share it.
+    fn key(address: &str, amount: u16) -> (String, u16) {
+        let address = address_bytes(address)
+            .map(hex::encode)
+            .unwrap_or_else(|| address.to_string());
+        (address, amount)
+    }
+
+    fn get(&self, key: &(String, u16)) -> Option<Vec<TransactionDetails>> {
+        let (transactions, fetched_at) = self.entries.get(key)?;
+        (fetched_at.elapsed() < self.ttl).then(|| transactions.clone())
+    }
+
+    /// Stores a freshly fetched history. Drops expired histories and those of the same address whose
+    /// newest transaction differs, as the address has seen a new transaction since they were fetched.
+    fn insert(&mut self, key: (String, u16), transactions: Vec<TransactionDetails>) {
+        let newest = transactions.last().map(|tx| tx.hash.clone());
+        let ttl = self.ttl;
+        self.entries.retain(|(address, _), (cached, fetched_at)| {
+            fetched_at.elapsed() < ttl
+                && (*address != key.0 || cached.last().map(|tx| &tx.hash) == newest.as_ref())
+        });
+        self.entries.insert(key, (transactions, Instant::now()));
+    }
+}
+
+/// Blocks buried under at least `confirmations` blocks, which are not expected to change anymore.
+struct BlockCache {
+    confirmations: u32,
+    /// Maximum number of blocks kept.
+    capacity: usize,
+    /// Highest block height seen so far.
+    head: u32,
+    /// Blocks by hash and whether they contain full transactions.
+    blocks: HashMap<(String, bool), Block>,
+    hashes: HashMap<u32, String>,
+    /// Keys of `blocks`, in the order they were cached.
+    order: VecDeque<(String, bool)>,
+}
+
+impl BlockCache {
+    fn by_hash(&self, hash: &str, full_transactions: bool) -> Option<Block> {
+        self.blocks
+            .get(&(hash.to_string(), full_transactions))
+            .cloned()
+    }
+
+    fn by_number(&self, block_number: u32, full_transactions: bool) -> Option<Block> {
+        let hash = self.hashes.get(&block_number)?;
+        self.by_hash(hash, full_transactions)
+    }
+
+    fn observe_block_number(&mut self, block_number: u32) {
+        self.head = self.head.max(block_number);
+    }
+
+    /// Stores `block` if it has enough confirmations, dropping the blocks cached first once the cache is full.
+    fn insert(&mut self, block: &Block, full_transactions: bool) {
+        self.observe_block_number(block.number);
+        if self.capacity == 0 || block.number.saturating_add(self.confirmations) > self.head {
+            return;
+        }
+        let key = (block.hash.clone(), full_transactions);
+        self.hashes.insert(block.number, block.hash.clone());
+        if self.blocks.insert(key.clone(), block.clone()).is_some() {
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            let Some(key) = self.order.pop_front() else {
+                break;
+            };
+            let Some(evicted) = self.blocks.remove(&key) else {
+                continue;
+            };
+            let other = (key.0, !key.1);
+            if !self.blocks.contains_key(&other)
+                && self.hashes.get(&evicted.number) == Some(&other.0)
+            {
+                self.hashes.remove(&evicted.number);
+            }
+        }
+    }
+}
+
+/// Spaces requests out so that at most one starts per `interval`.
+struct RateLimiter {
+    interval: Duration,
+    /// Earliest time the next request may start.
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Waits until the next request may start and reserves that slot.
+    async fn acquire(&self) {
+        let wait = {
+            let mut next = self.next.lock().unwrap();
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + self.interval;
+            slot - now
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Parameters that were already serialized, e.g. for logging.
+#[cfg(feature = "tracing")]
+struct RawParams(Option<Box<serde_json::value::RawValue>>);
+
+#[cfg(feature = "tracing")]
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<serde_json::value::RawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Serializes `params` and logs them with `method`, or `[redacted]` instead for sensitive methods.
+#[cfg(feature = "tracing")]
+fn log_request<P: ToRpcParams>(method: &str, params: P) -> Result<RawParams, Error> {
+    let params = params
+        .to_rpc_params()
+        .map_err(|e| Error::Rpc(ClientError::ParseError(e)))?;
+    if SENSITIVE_METHODS.contains(&method) {
+        tracing::debug!(method, params = "[redacted]", "request");
+    } else {
+        let logged = params.as_ref().map_or("[]", |params| params.get());
+        tracing::debug!(method, params = logged, "request");
+    }
+    Ok(RawParams(params))
+}
+
+/// Decodes the block header of `work`, checking its length.
+pub(crate) fn work_header(work: &GetWork) -> Result<Vec<u8>, Error> {
+    let header = hex::decode(&work.data)
+        .map_err(|e| Error::InvalidResponse(format!("Invalid work data: {}", e)))?;
+    if header.len() != mining::HEADER_SIZE {
+        return Err(Error::InvalidResponse(format!(
+            "Invalid work data: expected {} header bytes, got {}",
+            mining::HEADER_SIZE,
+            header.len()
+        )));
+    }
+    Ok(header)
+}
+
+/// Lowercases a block or transaction hash and strips an optional `0x` prefix, the form the node returns hashes in.
+fn normalize_hash(hash: &str) -> String {
+    hash.strip_prefix("0x")
+        .or_else(|| hash.strip_prefix("0X"))
+        .unwrap_or(hash)
+        .to_ascii_lowercase()
+}
+
+/// Sorts transactions oldest first by block number, with those not yet in a block last and ties broken by
+/// hash, and removes duplicates.
+fn sort_transactions(transactions: &mut Vec<TransactionDetails>) {
+    transactions.sort_by(|a, b| {
+        let height = |tx: &TransactionDetails| tx.block_number.unwrap_or(u32::MAX);
+        height(a).cmp(&height(b)).then_with(|| a.hash.cmp(&b.hash))
+    });
+    transactions.dedup_by(|a, b| a.hash == b.hash);
+}
+
+/// Checks that `hex` is an even number of hex digits, pointing at the first offending character, or
+/// at the end for a missing last digit.
+fn validate_hex(hex: &str) -> Result<(), Error> {
+    if let Some(position) = hex.bytes().position(|b| !b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidHex { position });
+    }
+    if hex.len() % 2 != 0 {
+        return Err(Error::InvalidHex {
+            position: hex.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that `peer_address` has the shape `ws[s]://host:port/peer-id`.
+fn validate_peer_address(peer_address: &str) -> Result<(), Error> {
+    let invalid = |reason: &str| {
+        Err(Error::InvalidArgument(format!(
+            "Invalid peer address `{}`: {}",
+            peer_address, reason
+        )))
+    };
+    let url = match Url::parse(peer_address) {
+        Ok(url) => url,
+        Err(e) => return invalid(&e.to_string()),
+    };
+    if !matches!(url.scheme(), "ws" | "wss") {
+        return invalid("expected scheme `ws` or `wss`");
+    }
+    if url.host_str().is_none() {
+        return invalid("missing host");
+    }
+    // `port()` is `None` for explicit default ports too, so look at the raw authority.
+    let authority = peer_address[url.scheme().len() + 3..]
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let has_port = authority
+        .rsplit_once(':')
+        .is_some_and(|(_, port)| !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()));
+    if !has_port {
+        return invalid("missing port");
+    }
+    if url.path().trim_matches('/').is_empty() {
+        return invalid("missing peer id");
+    }
+    Ok(())
+}
+
+/// Credentials sent in the `Authorization` header, which are never printed.
+#[derive(Clone)]
+enum Credentials {
+    Basic { username: String, password: String },
+    Bearer(String),
+}
+
+impl Credentials {
+    fn header_value(&self) -> String {
+        match self {
+            Credentials::Basic { username, password } => {
+                let mut s = username.clone();
+                s.push(':');
+                s.push_str(password);
+                format!(
+                    "Basic {}",
+                    &*base64::prelude::BASE64_STANDARD.encode(s.as_bytes())
+                )
+            }
+            Credentials::Bearer(token) => format!("Bearer {}", token),
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::Basic { .. } => f.write_str("Basic [redacted]"),
+            Credentials::Bearer(_) => f.write_str("Bearer [redacted]"),
+        }
+    }
+}
+
+/// Headers set with `ClientBuilder::header`. Their values may hold API keys, so only the names are printed.
+#[derive(Clone, Default)]
+struct Headers(Vec<(String, String)>);
+
+impl fmt::Debug for Headers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(name, _)| name))
+            .finish()
+    }
+}
+
+/// Builds a [`Client`] with custom settings.
+///
+/// The built client keeps a pool of keep-alive HTTP connections that is shared by all of its clones, so
+/// consecutive requests reuse open connections instead of connecting anew. Its size follows
+/// `max_concurrent_requests`, as every request in flight uses a connection of its own. Idle connections
+/// are closed after 90 seconds: jsonrpsee 0.24 builds the underlying HTTP client itself and offers no
+/// setting for the idle timeout, so it cannot be changed.
+///
+/// Every request carries a JSON-RPC id taken from a counter that starts at `0` and is shared by all
+/// clones of the client, so ids are unique per client. The requests of a batch use consecutive ids.
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    credentials: Option<Credentials>,
+    max_concurrent_requests: Option<usize>,
+    accept_encoding: bool,
+    id_format: Option<IdKind>,
+    request_timeout: Option<Duration>,
+    max_response_size: Option<u32>,
+    headers: Headers,
+}
+
+impl ClientBuilder {
+    pub fn new() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Authenticates every request using HTTP basic auth.
+    /// Replaces a token set with `bearer_token`.
+    pub fn credentials(mut self, username: &str, password: &str) -> ClientBuilder {
+        self.credentials = Some(Credentials::Basic {
+            username: username.to_string(),
+            password: password.to_string(),
+        });
+        self
+    }
+
+    /// Authenticates every request with a bearer token, as used by some hosted RPC providers.
+    /// Replaces credentials set with `credentials`.
+    pub fn bearer_token(mut self, token: &str) -> ClientBuilder {
+        self.credentials = Some(Credentials::Bearer(token.to_string()));
+        self
+    }
+
+    /// Limits the number of requests in flight at the same time, and with it the number of
+    /// connections the pool opens to the node. Further requests wait until one finishes.
+    pub fn max_concurrent_requests(mut self, max: usize) -> ClientBuilder {
+        self.max_concurrent_requests = Some(max);
+        self
+    }
+
+    /// Asks the node to compress responses with gzip or deflate and decompresses them. This can
+    /// greatly reduce the bandwidth used when fetching many full blocks. Disabled by default.
+    pub fn accept_encoding(mut self, enabled: bool) -> ClientBuilder {
+        self.accept_encoding = enabled;
+        self
+    }
+
+    /// Sets whether request ids are sent as JSON numbers (`IdKind::Number`, the default) or as
+    /// strings (`IdKind::String`, e.g. `"7"`), for proxies that key their logs on string ids.
+    /// `IdKind` is available as `nimiq_rpc::jsonrpsee::core::client::IdKind`.
+    pub fn id_format(mut self, id_format: IdKind) -> ClientBuilder {
+        self.id_format = Some(id_format);
+        self
+    }
+
+    /// Sets how long to wait for the response to a request, 60 seconds by default.
+    /// Calls that time out fail with a `NimiqError::Rpc` error.
+    pub fn request_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes, 10 MiB by default. Raise it to fetch
+    /// large blocks or accounts tree chunks.
+    pub fn max_response_size(mut self, max: u32) -> ClientBuilder {
+        self.max_response_size = Some(max);
+        self
+    }
+
+    /// Sends a header with every request, e.g. an API key of a hosted RPC provider.
+    /// A header set more than once keeps the last value, and a header named `Authorization` replaces
+    /// the one set with `credentials` or `bearer_token`.
+    pub fn header(mut self, name: &str, value: &str) -> ClientBuilder {
+        self.headers.0.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Builds the client. Fails if the URL does not use the `http` or `https` scheme, or if a header
+    /// set with `header` is not a valid HTTP header.
+    pub fn build(self, url: Url) -> Result<Client, Error> {
+        match url.scheme() {
+            "http" | "https" => {}
+            scheme => return Err(Error::UnsupportedScheme(scheme.to_string())),
+        }
+
+        Ok(Client {
+            agent: self.http_client(&url, HeaderMap::new())?,
+            url,
+            builder: self,
+            account_cache: None,
+            block_cache: None,
+            history_cache: None,
+            rate_limiter: None,
+            genesis_block_number: 1,
+            signed_transactions: Arc::default(),
+            on_request: None,
+            on_response: None,
+            metrics: None,
+        })
+    }
+
+    /// Builds an HTTP client with the settings of this builder that sends `extra_headers` with every
+    /// request, in addition to the `Authorization` header, which they override.
+    fn http_client(
+        &self,
+        url: &Url,
+        extra_headers: HeaderMap,
+    ) -> Result<HttpClient<Decompression<HttpBackend>>, Error> {
+        let mut headers = HeaderMap::new();
+        if let Some(credentials) = &self.credentials {
+            headers.insert(
+                "Authorization",
+                credentials
+                    .header_value()
+                    .parse()
+                    .map_err(|_| Error::InvalidCredentials)?,
+            );
+        }
+        for (name, value) in &self.headers.0 {
+            let header: HashMap<_, _> = [(name.clone(), value.clone())].into_iter().collect();
+            let header = HeaderMap::try_from(&header)
+                .map_err(|_| Error::InvalidArgument(format!("Invalid HTTP header `{}`", name)))?;
+            headers.extend(header);
+        }
+        headers.extend(extra_headers);
+
+        let decompression = DecompressionLayer::new()
+            .gzip(self.accept_encoding)
+            .deflate(self.accept_encoding);
+        let mut builder = HttpClientBuilder::default()
+            .set_headers(headers)
+            .set_http_middleware(ServiceBuilder::new().layer(decompression));
+        if let Some(max) = self.max_concurrent_requests {
+            builder = builder.max_concurrent_requests(max);
+        }
+        if let Some(id_format) = self.id_format {
+            builder = builder.id_format(id_format);
+        }
+        if let Some(timeout) = self.request_timeout {
+            builder = builder.request_timeout(timeout);
+        }
+        if let Some(max) = self.max_response_size {
+            builder = builder.max_response_size(max);
+        }
+        Ok(builder.build(url.as_str())?)
+    }
+}
+
+/// Settings of a [`Client`], to be loaded from a configuration file and passed to `Client::from_config`.
+///
+/// In a configuration file `credentials` is a `[username, password]` pair, `timeout` is given in
+/// seconds and `headers` is a list of `[name, value]` pairs. All fields but `url` are optional:
+///
+/// ```json
+/// {
+///     "url": "http://127.0.0.1:8648",
+///     "credentials": ["user", "password"],
+///     "timeout": 30,
+///     "headers": [["X-Api-Key", "secret"]]
+/// }
+/// ```
+#[derive(Clone, Deserialize)]
+pub struct ClientConfig {
+    pub url: Url,
+    /// Username and password for HTTP basic auth.
+    #[serde(default)]
+    pub credentials: Option<(String, String)>,
+    /// See `ClientBuilder::request_timeout`.
+    #[serde(default, deserialize_with = "optional_seconds")]
+    pub timeout: Option<Duration>,
+    /// See `ClientBuilder::max_response_size`.
+    #[serde(default)]
+    pub max_response_size: Option<u32>,
+    /// See `ClientBuilder::header`.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+}
+
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("url", &self.url.as_str())
+            .field(
+                "credentials",
+                &self.credentials.as_ref().map(|_| "[redacted]"),
+            )
+            .field("timeout", &self.timeout)
+            .field("max_response_size", &self.max_response_size)
+            .field(
+                "headers",
+                &self
+                    .headers
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Reads a duration given as a number of seconds.
+fn optional_seconds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Duration>, D::Error> {
+    Option::<f64>::deserialize(deserializer)?
+        .map(|seconds| Duration::try_from_secs_f64(seconds).map_err(serde::de::Error::custom))
+        .transpose()
 }
 
 impl Client {
+    /// Creates a client for the node at `url` with default settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the URL does not use the `http` or `https` scheme, e.g. for a `ws://` URL, as the client
+    /// only speaks JSON-RPC over HTTP. Use `ClientBuilder::build` to get `NimiqError::UnsupportedScheme`
+    /// instead.
     pub fn new(url: Url) -> Client {
-        Client {
-            agent: HttpClientBuilder::default().build(url).unwrap(),
-        }
+        ClientBuilder::new().build(url).unwrap_or_else(|error| {
+            panic!("{}, use ClientBuilder::build to handle this error", error)
+        })
     }
 
+    /// Creates a client for the node at `url` that authenticates every request using HTTP basic auth.
+    ///
+    /// # Panics
+    ///
+    /// Panics like `Client::new` if the URL does not use the `http` or `https` scheme.
     pub fn new_with_credentials(url: Url, username: String, password: String) -> Client {
-        let mut s = username;
-        s.push(':');
-        s.push_str(&password);
-        let auth = format!(
-            "Basic {}",
-            &*base64::prelude::BASE64_STANDARD.encode(s.as_bytes())
+        ClientBuilder::new()
+            .credentials(&username, &password)
+            .build(url)
+            .unwrap_or_else(|error| {
+                panic!("{}, use ClientBuilder::build to handle this error", error)
+            })
+    }
+
+    /// Builds a client from settings loaded e.g. from a configuration file.
+    /// Fails like `ClientBuilder::build`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{Client, ClientConfig};
+    /// let config: ClientConfig =
+    ///     serde_json::from_str(r#"{ "url": "http://seed-host.com:8648", "timeout": 30 }"#).unwrap();
+    /// let client = Client::from_config(config).unwrap();
+    /// ```
+    pub fn from_config(config: ClientConfig) -> Result<Client, Error> {
+        let mut builder = ClientBuilder::new();
+        if let Some((username, password)) = &config.credentials {
+            builder = builder.credentials(username, password);
+        }
+        if let Some(timeout) = config.timeout {
+            builder = builder.request_timeout(timeout);
+        }
+        if let Some(max) = config.max_response_size {
+            builder = builder.max_response_size(max);
+        }
+        for (name, value) in &config.headers {
+            builder = builder.header(name, value);
+        }
+        builder.build(config.url)
+    }
+
+    /// Enables a cache for `get_account`, shared with all clones of the returned client.
+    ///
+    /// Cached accounts are only dropped when this client sees the block height change, that is when
+    /// `block_number` or `node_status` returns a different height than before, or when
+    /// `clear_account_cache` is called. Without one of these calls, `get_account` keeps returning the
+    /// balances of the block the account was first fetched at, so poll `block_number` regularly when
+    /// fresh balances matter.
+    pub fn with_account_cache(mut self) -> Client {
+        self.account_cache = Some(Arc::default());
+        self
+    }
+
+    /// Drops all accounts cached by `get_account`. Does nothing if the cache is not enabled.
+    pub fn clear_account_cache(&self) {
+        if let Some(cache) = &self.account_cache {
+            let mut cache = cache.lock().unwrap();
+            cache.accounts.clear();
+            cache.block_number = None;
+        }
+    }
+
+    /// Enables a cache for blocks fetched by hash or number, shared with all clones of the returned client.
+    ///
+    /// Only blocks with at least `confirmations` blocks on top of them are cached, as shallower blocks may
+    /// still be replaced by a fork. The height is taken from the highest block this client has seen, either
+    /// returned by `block_number` and `node_status` or fetched itself, so blocks are cached more eagerly
+    /// once the chain height is known. At most `capacity` blocks are kept, a block fetched both with and
+    /// without full transactions counting twice; once the cache is full, the blocks cached first are dropped.
+    pub fn with_block_cache(mut self, confirmations: u32, capacity: usize) -> Client {
+        self.block_cache = Some(Arc::new(Mutex::new(BlockCache {
+            confirmations,
+            capacity,
+            head: 0,
+            blocks: HashMap::new(),
+            hashes: HashMap::new(),
+            order: VecDeque::new(),
+        })));
+        self
+    }
+
+    /// Enables a cache for `get_transactions_by_address`, shared with all clones of the returned client.
+    ///
+    /// Results are cached by address and amount for `ttl` after they were fetched. Before serving a cached
+    /// history, the client asks the node for the newest transaction of the address only, and fetches the
+    /// whole history anew if that transaction is not part of it, so a new transaction is never missed and
+    /// a cache hit costs a single small request. When the client fetches the history of an address anew
+    /// and sees a newest transaction different from that of the other cached histories of the address, it
+    /// drops them right away. Transactions of the other methods listing transactions by address are not cached.
+    pub fn with_history_cache(mut self, ttl: Duration) -> Client {
+        self.history_cache = Some(Arc::new(Mutex::new(HistoryCache {
+            ttl,
+            entries: HashMap::new(),
+        })));
+        self
+    }
+
+    /// Sets the number of the genesis block of the chain the node runs, 1 by default as on the Nimiq PoW
+    /// chain. `get_block_by_number` rejects lower block numbers without asking the node, so use
+    /// `with_genesis_block_number(0)` for an Albatross node whose genesis block has number 0.
+    pub fn with_genesis_block_number(mut self, genesis_block_number: u32) -> Client {
+        self.genesis_block_number = genesis_block_number;
+        self
+    }
+
+    /// Rejects block numbers below the genesis block, see `with_genesis_block_number`.
+    fn check_block_number(&self, block_number: u32) -> Result<(), Error> {
+        if block_number < self.genesis_block_number {
+            return Err(Error::InvalidArgument(format!(
+                "Block numbers start at {0}, the genesis block is block {0}",
+                self.genesis_block_number
+            )));
+        }
+        Ok(())
+    }
+
+    fn observe_block_number(&self, block_number: u32) {
+        if let Some(cache) = &self.account_cache {
+            cache.lock().unwrap().observe_block_number(block_number);
+        }
+        if let Some(cache) = &self.block_cache {
+            cache.lock().unwrap().observe_block_number(block_number);
+        }
+    }
+
+    fn cached_block(&self, id: &BlockId, full_transactions: bool) -> Option<Block> {
+        let cache = self.block_cache.as_ref()?.lock().unwrap();
+        match id {
+            BlockId::Hash(block_hash) => cache.by_hash(block_hash, full_transactions),
+            BlockId::Number(block_number) => cache.by_number(*block_number, full_transactions),
+            BlockId::Latest => None,
+        }
+    }
+
+    fn cache_block(&self, block: &Block, full_transactions: bool) {
+        if let Some(cache) = &self.block_cache {
+            cache.lock().unwrap().insert(block, full_transactions);
+        }
+    }
+
+    /// Limits the client to `per_second` requests per second, shared with all clones of the returned client.
+    ///
+    /// Requests are spaced evenly: a burst of calls is serialized and each call waits until
+    /// `1 / per_second` seconds have passed since the previous one started, which adds latency but keeps
+    /// the client under the limit of hosted providers. A batch counts as a single request.
+    ///
+    /// Panics if `per_second` is zero.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Client {
+        assert!(
+            per_second > 0,
+            "Rate limit must be at least one request per second"
         );
-        let mut headers = HeaderMap::new();
-        headers.insert("Authorization", auth.parse().unwrap());
-        Client {
-            agent: HttpClientBuilder::default()
-                .set_headers(headers)
-                .build(url)
-                .unwrap(),
+        self.rate_limiter = Some(Arc::new(RateLimiter {
+            interval: Duration::from_secs(1) / per_second,
+            next: Mutex::new(Instant::now()),
+        }));
+        self
+    }
+
+    /// Waits until the rate limit, if any, allows another request.
+    async fn throttle(&self) {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Calls `interceptor` with the method name before every request of the returned client and its clones,
+    /// e.g. to count calls per method. Replaces a previously set interceptor.
+    ///
+    /// The interceptor runs after waiting for the rate limit, right before the request is sent. Batch requests
+    /// are reported once, as method `batch`.
+    pub fn with_interceptor<F>(mut self, interceptor: F) -> Client
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_request = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Calls `hook` after every request of the returned client and its clones with the method name, the time
+    /// the request took and whether it failed, e.g. to record latencies and errors per method. Replaces a
+    /// previously set hook.
+    ///
+    /// Like the interceptor, the hook does not see time spent waiting for the rate limit, and batch requests
+    /// are reported once, as method `batch`. A request whose future is dropped before it completes is not
+    /// reported.
+    pub fn with_response_hook<F>(mut self, hook: F) -> Client
+    where
+        F: Fn(&str, Duration, Result<(), &Error>) + Send + Sync + 'static,
+    {
+        self.on_response = Some(Arc::new(hook));
+        self
+    }
+
+    /// Enables call statistics per method, shared with all clones of the returned client and read with
+    /// `metrics`. Like the response hook, they do not include time spent waiting for the rate limit.
+    pub fn with_metrics(mut self) -> Client {
+        self.metrics = Some(Arc::default());
+        self
+    }
+
+    /// Returns the call statistics, or `None` if they are not enabled with `with_metrics`.
+    pub fn metrics(&self) -> Option<&Metrics> {
+        self.metrics.as_deref()
+    }
+
+    /// Runs a request, calling the interceptor and the response hook and recording metrics if they are set.
+    async fn observe<T>(
+        &self,
+        method: &str,
+        call: impl Future<Output = Result<T, Error>>,
+    ) -> Result<T, Error> {
+        if let Some(interceptor) = &self.on_request {
+            interceptor(method);
+        }
+        if self.on_response.is_none() && self.metrics.is_none() {
+            return call.await;
+        }
+        let start = Instant::now();
+        let result = call.await;
+        let elapsed = start.elapsed();
+        if let Some(hook) = &self.on_response {
+            hook(method, elapsed, result.as_ref().map(|_| ()));
         }
+        if let Some(metrics) = &self.metrics {
+            metrics.record(method, elapsed, result.is_err());
+        }
+        result
+    }
+
+    /// Sends a batch request, calling the hooks like for single requests.
+    async fn batch_request<'a, R: DeserializeOwned + fmt::Debug + 'a>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error> {
+        self.observe("batch", async {
+            Ok(self.agent.batch_request(batch).await?)
+        })
+        .await
+    }
+
+    /// Calls any method of the node, including methods this crate does not wrap yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Name of the method.
+    /// * `Params`: Parameters of the call, e.g. built with `rpc_params!`.
+    ///
+    /// # Returns
+    ///
+    /// The result of the call, deserialized into `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{jsonrpsee::rpc_params, Client};
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result: Result<u32, _> = client.call("blockNumber", rpc_params![]).await;
+    /// # })
+    /// ```
+    pub async fn call<P: ToRpcParams + Send, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R, Error> {
+        self.request(method, params).await
+    }
+
+    /// Calls any method of the node like `call`, but returns the result as the node sent it, e.g. to inspect
+    /// a response a typed method fails to deserialize or to forward it unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Name of the method.
+    /// * `Params`: Parameters of the call, e.g. built with `rpc_params!`.
+    ///
+    /// # Returns
+    ///
+    /// The `result` member of the response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{jsonrpsee::rpc_params, Client};
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.call_raw("getBlockByNumber", rpc_params![1234, false]).await;
+    /// # })
+    /// ```
+    pub async fn call_raw<P: ToRpcParams + Send>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<Value, Error> {
+        self.request(method, params).await
+    }
+
+    /// Calls any method of the node like `call`, sending `headers` with this request only, e.g. a
+    /// routing header required by a gateway. The headers are sent in addition to the `Authorization`
+    /// header, which they override if they contain one.
+    ///
+    /// `Note` The underlying HTTP client sends the same headers with every request, so this builds a
+    /// new one for the call. It opens a new connection instead of reusing the pooled ones, which costs
+    /// an extra TCP (and TLS) handshake per call, and its request ids start at `0` again. Prefer headers
+    /// set on the [`ClientBuilder`] where they do not change between calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Name of the method.
+    /// * `Params`: Parameters of the call, e.g. built with `rpc_params!`.
+    /// * `HeaderMap`: Headers to send with the request.
+    ///
+    /// # Returns
+    ///
+    /// The result of the call, deserialized into `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{jsonrpsee::http_client::HeaderMap, jsonrpsee::rpc_params, Client};
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Route", "eu-1".parse().unwrap());
+    /// let result: Result<u32, _> = client
+    ///     .call_with_headers("blockNumber", rpc_params![], headers)
+    ///     .await;
+    /// # })
+    /// ```
+    pub async fn call_with_headers<P: ToRpcParams + Send, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+        headers: HeaderMap,
+    ) -> Result<R, Error> {
+        let agent = self.builder.http_client(&self.url, headers)?;
+        #[cfg(feature = "tracing")]
+        let params = log_request(method, params)?;
+        self.throttle().await;
+        self.observe(method, async {
+            agent
+                .request(method, params)
+                .await
+                .map_err(|e| Error::from_call(method, e))
+        })
+        .await
+    }
+
+    async fn request<P: ToRpcParams + Send, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R, Error> {
+        #[cfg(feature = "tracing")]
+        let params = log_request(method, params)?;
+        self.throttle().await;
+        self.observe(method, async {
+            self.agent
+                .request(method, params)
+                .await
+                .map_err(|e| Error::from_call(method, e))
+        })
+        .await
+    }
+
+    /// Returns a list of addresses owned by client.
+    /// `Note` The node has no method listing the public keys of its wallets. They are only returned once, by `create_account`, so store the returned [`Wallet`] if the public key is needed later.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// Vector of accounts owned by the client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.accounts();
+    /// # })
+    /// ```
+    pub async fn accounts(&self) -> Result<Vec<Account>, Error> {
+        let params = rpc_params![];
+        self.request("accounts", params).await
+    }
+
+    /// Returns the height of most recent block.
+    /// `Note` Heights are `u32` throughout the crate, like in the nodes themselves. With one block per minute on the proof-of-work chain, heights stay below `u32::MAX` for more than 8000 years, and with one block per second on Albatross for more than 130 years.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// The current block height the client is on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.block_number().await;
+    /// # })
+    /// ```
+    pub async fn block_number(&self) -> Result<u32, Error> {
+        let params = rpc_params![];
+        let block_number = self.request("blockNumber", params).await?;
+        self.observe_block_number(block_number);
+        Ok(block_number)
+    }
+
+    /// Returns information on the current consensus state.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// String describing the consensus state. `"established"` is the value for a good state, other values indicate bad state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.consensus().await;
+    /// # })
+    /// ```
+    pub async fn consensus(&self) -> Result<String, Error> {
+        let params = rpc_params![];
+        self.request("consensus", params).await
+    }
+
+    /// Creates a new account and stores its private key in the client store.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// Information on the wallet that was created using the command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.create_account().await;
+    /// # })
+    /// ```
+    pub async fn create_account(&self) -> Result<Wallet, Error> {
+        let params = rpc_params![];
+        self.request("createAccount", params).await
+    }
+
+    /// Returns `true` if the node can sign for the address, i.e. the address is one of the node's `accounts` and unlocked.
+    /// `Note` Only Albatross nodes lock accounts. They are asked with `isAccountUnlocked`, and accounts of nodes that do not know the method are always unlocked.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: The address, user friendly or hex-encoded.
+    ///
+    /// # Returns
+    ///
+    /// `true` if signing with the address can succeed. Fails with `NimiqError::InvalidArgument` if the address is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.can_sign("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN").await;
+    /// # })
+    /// ```
+    pub async fn can_sign(&self, address: &str) -> Result<bool, Error> {
+        let wanted: Address = address
+            .parse()
+            .map_err(|e| Error::InvalidArgument(format!("Invalid address `{}`: {}", address, e)))?;
+        let accounts = self.accounts().await?;
+        let owned = accounts
+            .iter()
+            .any(|account| account.info().address.parse() == Ok(wanted));
+        if !owned {
+            return Ok(false);
+        }
+        let params = rpc_params![wanted.to_user_friendly()];
+        match self.request("isAccountUnlocked", params).await {
+            Err(Error::MethodNotFound(_)) => Ok(true),
+            result => result,
+        }
+    }
+
+    /// Creates and signs a transaction without sending it. The transaction can then be send via `sendRawTransaction` without accidentally replaying it.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_transaction`: `&primitives::OutgoingTransaction`
+    ///
+    /// # Returns
+    ///
+    /// The Hex-encoded transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
+    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+    ///    value: 100, //Lunas
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
+    /// };
+    /// let result = client.create_raw_transaction(&tx).await;
+    /// # })
+    /// ```
+    pub async fn create_raw_transaction(
+        &self,
+        raw_transaction: &OutgoingTransaction,
+    ) -> Result<String, Error> {
+        let params = rpc_params![raw_transaction];
+        self.request("createRawTransaction", params).await
+    }
+
+    /// Returns details for the account of given address.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Address to check for balance.
+    ///
+    /// # Returns
+    ///
+    /// Details about the account. Returns the default empty basic account for non-existing accounts.
+    /// Served from the cache if enabled with `with_account_cache`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_account("ad25610feb43d75307763d3f010822a757027429").await;
+    /// # })
+    /// ```
+    pub async fn get_account(&self, id: &str) -> Result<Account, Error> {
+        let key = AccountCache::key(id);
+        let mut block_number = None;
+        if let Some(cache) = &self.account_cache {
+            let cache = cache.lock().unwrap();
+            if let Some(account) = cache.accounts.get(&key) {
+                return Ok(account.clone());
+            }
+            block_number = cache.block_number;
+        }
+        let params = rpc_params![id];
+        let account: Account = self.request("getAccount", params).await?;
+        if let Some(cache) = &self.account_cache {
+            cache
+                .lock()
+                .unwrap()
+                .insert(key, account.clone(), block_number);
+        }
+        Ok(account)
+    }
+
+    /// Returns details for the accounts of several addresses in a single batch request.
+    ///
+    /// # Arguments
+    ///
+    /// * `Array<String>`: Addresses of the accounts to gather.
+    ///
+    /// # Returns
+    ///
+    /// Details about the accounts, in the same order as the addresses, including the contract fields of vesting and HTLC accounts.
+    /// Accounts found in the cache enabled with `with_account_cache` are not requested again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_accounts(&["ad25610feb43d75307763d3f010822a757027429", "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK"]).await;
+    /// # })
+    /// ```
+    pub async fn get_accounts(&self, addresses: &[&str]) -> Result<Vec<Account>, Error> {
+        let keys: Vec<String> = addresses.iter().map(|id| AccountCache::key(id)).collect();
+        let mut block_number = None;
+        let mut accounts: Vec<Option<Account>> = match &self.account_cache {
+            Some(cache) => {
+                let cache = cache.lock().unwrap();
+                block_number = cache.block_number;
+                keys.iter()
+                    .map(|key| cache.accounts.get(key).cloned())
+                    .collect()
+            }
+            None => vec![None; addresses.len()],
+        };
+        let missing: Vec<usize> = (0..addresses.len())
+            .filter(|i| accounts[*i].is_none())
+            .collect();
+        if !missing.is_empty() {
+            let mut batch = BatchRequestBuilder::new();
+            for i in &missing {
+                batch.insert("getAccount", rpc_params![addresses[*i]])?;
+            }
+            self.throttle().await;
+            let entries = self.batch_request::<Account>(batch).await?;
+            if entries.len() != missing.len() {
+                return Err(Error::InvalidResponse(
+                    "Incomplete batch response".to_string(),
+                ));
+            }
+            for (i, entry) in missing.into_iter().zip(entries) {
+                let account = entry.map_err(|e| {
+                    Error::from_call("getAccount", ClientError::Call(e.into_owned()))
+                })?;
+                if let Some(cache) = &self.account_cache {
+                    cache
+                        .lock()
+                        .unwrap()
+                        .insert(keys[i].clone(), account.clone(), block_number);
+                }
+                accounts[i] = Some(account);
+            }
+        }
+        Ok(accounts.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Returns an Accounts tree chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Block hash on which the accounts tree chunk is going to be retrieved.
+    /// * `String`: Start prefix for the accounts tree chunk to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// An Accounts tree chunk containing the tail prefix and the length of the chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_accounts_tree_chunk("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", "").await;
+    /// # })
+    /// ```
+    pub async fn get_accounts_tree_chunk(
+        &self,
+        block_hash: &str,
+        start_prefix: &str,
+    ) -> Result<AccountsTreeChunk, Error> {
+        let params = rpc_params![normalize_hash(block_hash), start_prefix];
+        self.request("getAccountsTreeChunk", params).await
+    }
+
+    /// Walks the whole accounts tree at a block by requesting one `get_accounts_tree_chunk` after the other, and yields its nodes in prefix order.
+    /// `Note` The node decides how many nodes a chunk holds. The `tail` of a chunk is the prefix the next chunk is requested from, and a node the next chunk repeats is yielded only once. Only one chunk is held in memory at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block whose accounts tree is walked.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the nodes of the accounts tree. It ends after the last chunk, or after the first error, which is yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let nodes = client.accounts_tree_stream("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87");
+    /// ```
+    pub fn accounts_tree_stream(
+        &self,
+        block_hash: &str,
+    ) -> impl Stream<Item = Result<AccountsTreeNode, Error>> + Send + 'static {
+        struct State {
+            client: Client,
+            block_hash: String,
+            /// Prefix to request the next chunk from, `None` once the tree is exhausted.
+            next: Option<String>,
+            last: Option<String>,
+            nodes: VecDeque<AccountsTreeNode>,
+        }
+
+        let state = State {
+            client: self.clone(),
+            block_hash: block_hash.to_string(),
+            next: Some(String::new()),
+            last: None,
+            nodes: VecDeque::new(),
+        };
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(node) = state.nodes.pop_front() {
+                    state.last = Some(node.prefix.clone());
+                    return Some((Ok(node), state));
+                }
+                let start = state.next.take()?;
+                let chunk = match state
+                    .client
+                    .get_accounts_tree_chunk(&state.block_hash, &start)
+                    .await
+                {
+                    Ok(chunk) => chunk,
+                    Err(error) => return Some((Err(error), state)),
+                };
+                let last = state.last.clone();
+                state.nodes.extend(
+                    chunk
+                        .nodes
+                        .into_iter()
+                        .filter(|node| last.as_ref().map_or(true, |last| node.prefix > *last)),
+                );
+                // A chunk without new nodes or without progress ends the walk.
+                if !state.nodes.is_empty() && !chunk.tail.is_empty() && chunk.tail != start {
+                    state.next = Some(chunk.tail);
+                }
+            }
+        })
+    }
+
+    /// Returns the staking contract of an Albatross node.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// Details about the staking contract.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_staking_contract().await;
+    /// # })
+    /// ```
+    #[cfg(feature = "pos")]
+    pub async fn get_staking_contract(&self) -> Result<crate::pos::StakingContract, Error> {
+        let params = rpc_params![crate::pos::STAKING_CONTRACT_ADDRESS];
+        self.request("getAccount", params).await
+    }
+
+    /// Returns the current epoch of an Albatross node.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// The number of the epoch the head of the chain is in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_epoch_number().await;
+    /// # })
+    /// ```
+    #[cfg(feature = "pos")]
+    pub async fn get_epoch_number(&self) -> Result<u32, Error> {
+        let params = rpc_params![];
+        self.request("getEpochNumber", params).await
+    }
+
+    /// Returns the current batch of an Albatross node.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// The number of the batch the head of the chain is in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_batch_number().await;
+    /// # })
+    /// ```
+    #[cfg(feature = "pos")]
+    pub async fn get_batch_number(&self) -> Result<u32, Error> {
+        let params = rpc_params![];
+        self.request("getBatchNumber", params).await
+    }
+
+    /// Returns the inherents (rewards and slashes) applied by a block of an Albatross node.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: The height of the block.
+    ///
+    /// # Returns
+    ///
+    /// Vector of inherents of the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_inherents_by_block_number(3456060).await;
+    /// # })
+    /// ```
+    #[cfg(feature = "pos")]
+    pub async fn get_inherents_by_block_number(
+        &self,
+        block_number: u32,
+    ) -> Result<Vec<crate::pos::Inherent>, Error> {
+        let params = rpc_params![block_number];
+        self.request("getInherentsByBlockNumber", params).await
+    }
+
+    /// Returns the balance of the account of given address.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Address to check for balance.
+    ///
+    /// # Returns
+    ///
+    /// Details about the account. The current balance at the specified address (in smallest unit).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_balance("ad25610feb43d75307763d3f010822a757027429").await;
+    /// # })
+    /// ```
+    pub async fn get_balance(&self, id: &str) -> Result<u64, Error> {
+        /// Accepts the balance as number or string, like the amounts in `primitives`.
+        #[derive(serde::Deserialize)]
+        struct Balance(#[serde(deserialize_with = "de_u64_flexible::deserialize")] u64);
+
+        let params = rpc_params![id];
+        let Balance(balance) = self.request("getBalance", params).await?;
+        Ok(balance)
+    }
+
+    /// Returns information about a block by hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block to gather information on.
+    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    ///
+    /// # Returns
+    ///
+    /// A block object or `null` when no block was found.
+    /// Served from the cache if enabled with `with_block_cache`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_by_hash("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", false).await;
+    /// # })
+    /// ```
+    pub async fn get_block_by_hash(
+        &self,
+        block_hash: &str,
+        full_transactions: bool,
+    ) -> Result<Block, Error> {
+        let block_hash = normalize_hash(block_hash);
+        let id = BlockId::Hash(block_hash.clone());
+        if let Some(block) = self.cached_block(&id, full_transactions) {
+            return Ok(block);
+        }
+        let params = rpc_params![block_hash, full_transactions];
+        let block: Block = self.request("getBlockByHash", params).await?;
+        self.cache_block(&block, full_transactions);
+        Ok(block)
+    }
+
+    /// Returns information about a block by hash, like `get_block_by_hash`, and checks that the node returned the requested block.
+    /// This guards against misconfigured proxies or caches answering with a different block.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block to gather information on, in upper or lower case, with or without `0x` prefix.
+    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    ///
+    /// # Returns
+    ///
+    /// A block object, or `NimiqError::HashMismatch` if the hash of the returned block differs from the requested one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_by_hash_verified("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", false).await;
+    /// # })
+    /// ```
+    pub async fn get_block_by_hash_verified(
+        &self,
+        block_hash: &str,
+        full_transactions: bool,
+    ) -> Result<Block, Error> {
+        let block = self
+            .get_block_by_hash(block_hash, full_transactions)
+            .await?;
+        let expected = normalize_hash(block_hash);
+        if block.hash != expected {
+            return Err(Error::HashMismatch {
+                expected,
+                actual: block.hash,
+            });
+        }
+        Ok(block)
+    }
+
+    /// Checks whether a block is on the main chain and has at least `min_confirmations` confirmations.
+    /// `Note` The block at the head of the chain has one confirmation. A block that is not at its height on the main chain, e.g. because it was orphaned by a fork, counts as unconfirmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block, in upper or lower case, with or without `0x` prefix.
+    /// * `Int`: The number of confirmations the block needs.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the block is on the main chain with enough confirmations, `false` if it is unknown, orphaned or too recent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.is_block_confirmed("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", 10).await;
+    /// # })
+    /// ```
+    pub async fn is_block_confirmed(
+        &self,
+        block_hash: &str,
+        min_confirmations: u32,
+    ) -> Result<bool, Error> {
+        let head = self.block_number().await?;
+        let Some(block) = self
+            .get_block(BlockId::Hash(block_hash.to_string()), false)
+            .await?
+        else {
+            return Ok(false);
+        };
+        let confirmations = (head + 1).saturating_sub(block.number);
+        if confirmations < min_confirmations {
+            return Ok(false);
+        }
+        let canonical = self.get_block(BlockId::Number(block.number), false).await?;
+        Ok(canonical.is_some_and(|canonical| canonical.hash == block.hash))
+    }
+
+    /// Returns information about a block by block number.
+    /// `Note` Block numbers start at 1, which is the genesis block. Asking for block 0 fails with `NimiqError::InvalidArgument`, unless the client was set up with `with_genesis_block_number(0)`, as needed for Albatross nodes whose genesis block has number 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: The height of the block to gather information on.
+    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    ///
+    /// # Returns
+    ///
+    /// A block object or `null` when no block was found.
+    /// Served from the cache if enabled with `with_block_cache`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_by_number(1234, false).await;
+    /// # })
+    /// ```
+    pub async fn get_block_by_number(
+        &self,
+        block_number: u32,
+        full_transactions: bool,
+    ) -> Result<Block, Error> {
+        self.check_block_number(block_number)?;
+        if let Some(block) = self.cached_block(&BlockId::Number(block_number), full_transactions) {
+            return Ok(block);
+        }
+        let params = rpc_params![block_number, full_transactions];
+        let block: Block = self.request("getBlockByNumber", params).await?;
+        self.cache_block(&block, full_transactions);
+        Ok(block)
+    }
+
+    /// Returns the blocks from `start` to `end`, both inclusive, in ascending order of their block number.
+    /// `Note` Up to 8 blocks are requested at the same time. If any block of the range is missing or cannot be fetched, the whole call fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: The height of the first block.
+    /// * `Int`: The height of the last block.
+    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    ///
+    /// # Returns
+    ///
+    /// The blocks of the range, or `NimiqError::InvalidArgument` if `start` is greater than `end`.
+    /// Blocks are served from the cache if enabled with `with_block_cache`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_range(1000, 1099, false).await;
+    /// # })
+    /// ```
+    pub async fn get_block_range(
+        &self,
+        start: u32,
+        end: u32,
+        full_transactions: bool,
+    ) -> Result<Vec<Block>, Error> {
+        if start > end {
+            return Err(Error::InvalidArgument(format!(
+                "Block range start {} is greater than its end {}",
+                start, end
+            )));
+        }
+        // `buffered` yields the blocks in the order of the range, whatever order they arrive in.
+        stream::iter(start..=end)
+            .map(|block_number| self.get_block_by_number(block_number, full_transactions))
+            .buffered(BLOCK_RANGE_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Returns the header fields and the number of transactions of a block by block number.
+    /// `Note` There is no RPC method for headers only. The block is fetched with transaction hashes instead of full transactions, and the hashes already give the count, so a single request suffices and no `getBlockTransactionCountByNumber` call is needed. Blocks are served from the cache if enabled with `with_block_cache`.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: The height of the block to gather information on.
+    ///
+    /// # Returns
+    ///
+    /// A summary of the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_summary_by_number(1234).await;
+    /// # })
+    /// ```
+    pub async fn get_block_summary_by_number(
+        &self,
+        block_number: u32,
+    ) -> Result<BlockSummary, Error> {
+        Ok(self.get_block_by_number(block_number, false).await?.into())
+    }
+
+    /// Returns information about a block by hash, block number or the latest block.
+    ///
+    /// # Arguments
+    ///
+    /// * `BlockId`: Hash or height of the block to gather information on, or `BlockId::Latest` for the head of the chain.
+    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    ///
+    /// # Returns
+    ///
+    /// A block object or `None` when no block was found.
+    /// Fails with `NimiqError::InvalidArgument` for block numbers below the genesis block, like `get_block_by_number`.
+    /// Served from the cache if enabled with `with_block_cache`, except for `BlockId::Latest`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{primitives::BlockId, Client};
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block(BlockId::Number(1234), false).await;
+    /// # })
+    /// ```
+    pub async fn get_block(
+        &self,
+        id: BlockId,
+        full_transactions: bool,
+    ) -> Result<Option<Block>, Error> {
+        let id = match id {
+            BlockId::Hash(block_hash) => BlockId::Hash(normalize_hash(&block_hash)),
+            id => id,
+        };
+        if let BlockId::Number(block_number) = id {
+            self.check_block_number(block_number)?;
+        }
+        if let Some(block) = self.cached_block(&id, full_transactions) {
+            return Ok(Some(block));
+        }
+        let block: Option<Block> = match id {
+            BlockId::Hash(block_hash) => {
+                let params = rpc_params![block_hash, full_transactions];
+                self.request("getBlockByHash", params).await?
+            }
+            BlockId::Number(block_number) => {
+                let params = rpc_params![block_number, full_transactions];
+                self.request("getBlockByNumber", params).await?
+            }
+            BlockId::Latest => {
+                let block_number = self.block_number().await?;
+                let params = rpc_params![block_number, full_transactions];
+                self.request("getBlockByNumber", params).await?
+            }
+        };
+        if let Some(block) = &block {
+            self.cache_block(block, full_transactions);
+        }
+        Ok(block)
+    }
+
+    /// Returns a template to build the next block for mining. This will consider pool instructions when connected to a pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// A block template object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_template().await;
+    /// # })
+    /// ```
+    pub async fn get_block_template(&self) -> Result<FullBlock, Error> {
+        let params = rpc_params![];
+        self.request("getBlockTemplate", params).await
+    }
+
+    /// Returns the number of transactions in a block from a block matching the given block hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions in the block found. Fails when no block was found, use `get_block_transaction_count_by_hash_opt` to tell a missing block from an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_transaction_count_by_hash("dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f").await;
+    /// # })
+    /// ```
+    pub async fn get_block_transaction_count_by_hash(
+        &self,
+        block_hash: &str,
+    ) -> Result<u16, Error> {
+        let params = rpc_params![normalize_hash(block_hash)];
+        self.request("getBlockTransactionCountByHash", params).await
+    }
+
+    /// Returns the number of transactions in a block matching the given block hash, or `None` if there is no such block.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions in the block found, or `None` when no block was found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_transaction_count_by_hash_opt("dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f").await;
+    /// # })
+    /// ```
+    pub async fn get_block_transaction_count_by_hash_opt(
+        &self,
+        block_hash: &str,
+    ) -> Result<Option<u16>, Error> {
+        let params = rpc_params![normalize_hash(block_hash)];
+        self.request("getBlockTransactionCountByHash", params).await
+    }
+
+    /// Returns the number of transactions in a block matching the given block number.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: Height of the block.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions in the block found. Fails when no block was found, use `get_block_transaction_count_by_number_opt` to tell a missing block from an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_transaction_count_by_number(76415).await;
+    /// # })
+    /// ```
+    pub async fn get_block_transaction_count_by_number(
+        &self,
+        block_number: u32,
+    ) -> Result<u16, Error> {
+        let params = rpc_params![block_number];
+        self.request("getBlockTransactionCountByNumber", params)
+            .await
+    }
+
+    /// Returns the number of transactions in a block matching the given block number, or `None` if there is no such block.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: Height of the block.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions in the block found, or `None` when no block was found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_block_transaction_count_by_number_opt(76415).await;
+    /// # })
+    /// ```
+    pub async fn get_block_transaction_count_by_number_opt(
+        &self,
+        block_number: u32,
+    ) -> Result<Option<u16>, Error> {
+        let params = rpc_params![block_number];
+        self.request("getBlockTransactionCountByNumber", params)
+            .await
+    }
+
+    /// Deserializes a signed transaction and returns its details without sending it.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: The hex encoded signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A transaction object as the node would see it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_raw_transaction_info("00c3....d207").await;
+    /// # })
+    /// ```
+    pub async fn get_raw_transaction_info(
+        &self,
+        raw_transaction: &str,
+    ) -> Result<TransactionDetails2, Error> {
+        let params = rpc_params![raw_transaction];
+        self.request("getRawTransactionInfo", params).await
+    }
+
+    /// Returns the hash of a signed transaction as computed by the node, e.g. to wait for its confirmation before or after sending it.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: The hex encoded signed transaction
+    ///
+    /// # Returns
+    ///
+    /// The Hex-encoded transaction hash, the same that `send_raw_transaction` returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.transaction_hash_of("00c3....d207").await;
+    /// # })
+    /// ```
+    pub async fn transaction_hash_of(&self, raw_transaction: &str) -> Result<String, Error> {
+        Ok(self.get_raw_transaction_info(raw_transaction).await?.hash)
+    }
+
+    /// Returns information about a transaction by block hash and transaction index position.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of the block containing the transaction
+    /// * `Int`: Index of the transaction in the block
+    ///
+    /// # Returns
+    ///
+    /// A transaction object or `null` when no transaction was found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transaction_by_block_hash_and_index("dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f", 20).await;
+    /// # })
+    /// ```
+    pub async fn get_transaction_by_block_hash_and_index(
+        &self,
+        block_hash: &str,
+        index: u16,
+    ) -> Result<Transaction, Error> {
+        let params = rpc_params![normalize_hash(block_hash), index];
+        self.request("getTransactionByBlockHashAndIndex", params)
+            .await
+    }
+
+    /// Returns the full transactions of a block fetched with only the hashes of its transactions, in a single batch request.
+    /// `Note` The transactions are fetched by block hash and index, like `get_transaction_by_block_hash_and_index`. Transactions the block already contains in full are not requested again.
+    ///
+    /// # Arguments
+    ///
+    /// * `Block`: A block, usually fetched with `full_transactions` set to `false`.
+    ///
+    /// # Returns
+    ///
+    /// The transactions of the block, in block order. Fails with `NimiqError::HashMismatch` if the node returns a different transaction than the block lists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// if let Ok(block) = client.get_block_by_number(1234, false).await {
+    ///     let transactions = client.enrich_block_transactions(&block).await;
+    /// }
+    /// # })
+    /// ```
+    pub async fn enrich_block_transactions(
+        &self,
+        block: &Block,
+    ) -> Result<Vec<Transaction>, Error> {
+        let mut transactions: Vec<Option<Transaction>> = block
+            .transactions
+            .iter()
+            .map(|tx| match tx {
+                TxOrHash::Full(transaction) => Some(transaction.clone()),
+                TxOrHash::Hash(_) => None,
+            })
+            .collect();
+        let missing: Vec<usize> = (0..transactions.len())
+            .filter(|i| transactions[*i].is_none())
+            .collect();
+        if !missing.is_empty() {
+            let mut batch = BatchRequestBuilder::new();
+            for i in &missing {
+                batch.insert(
+                    "getTransactionByBlockHashAndIndex",
+                    rpc_params![&block.hash, i],
+                )?;
+            }
+            self.throttle().await;
+            let entries = self.batch_request::<Transaction>(batch).await?;
+            if entries.len() != missing.len() {
+                return Err(Error::InvalidResponse(
+                    "Incomplete batch response".to_string(),
+                ));
+            }
+            for (i, entry) in missing.into_iter().zip(entries) {
+                let transaction = entry.map_err(|e| {
+                    Error::from_call(
+                        "getTransactionByBlockHashAndIndex",
+                        ClientError::Call(e.into_owned()),
+                    )
+                })?;
+                let expected = normalize_hash(block.transactions.get(i).unwrap().hash());
+                if transaction.hash != expected {
+                    return Err(Error::HashMismatch {
+                        expected,
+                        actual: transaction.hash,
+                    });
+                }
+                transactions[i] = Some(transaction);
+            }
+        }
+        Ok(transactions.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Returns information about a transaction by block number and transaction index position.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: Height of the block containing the transaction
+    /// * `Int`: Index of the transaction in the block
+    ///
+    /// # Returns
+    ///
+    /// A transaction object or `null` when no transaction was found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transaction_by_block_number_and_index(76415, 20).await;
+    /// # })
+    /// ```
+    pub async fn get_transaction_by_block_number_and_index(
+        &self,
+        block_number: u32,
+        index: u16,
+    ) -> Result<Transaction, Error> {
+        let params = rpc_params![block_number, index];
+        self.request("getTransactionByBlockNumberAndIndex", params)
+            .await
+    }
+
+    /// Returns the information about a transaction requested by transaction hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of a transaction
+    ///
+    /// # Returns
+    ///
+    /// A transaction object or `null` when no transaction was found. Includes the signature proof in `proof`, see [`TransactionDetails::signature_proof_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transaction_by_hash("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// # })
+    /// ```
+    pub async fn get_transaction_by_hash(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionDetails, Error> {
+        let params = rpc_params![normalize_hash(transaction_hash)];
+        self.request("getTransactionByHash", params).await
+    }
+
+    /// Returns the information about a transaction requested by transaction hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of a transaction
+    ///
+    /// # Returns
+    ///
+    /// A transaction object or `null` when no transaction was found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transaction_by_hash_2("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// # })
+    /// ```
+    pub async fn get_transaction_by_hash_2(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionDetails2, Error> {
+        let params = rpc_params![normalize_hash(transaction_hash)];
+        self.request("getTransactionByHash2", params).await
+    }
+
+    /// Returns the receipt of a transaction by transaction hash.
+    /// `Note` That the receipt is not available for pending transactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of a transaction
+    ///
+    /// # Returns
+    ///
+    /// A transaction receipt object, or `null` when no receipt was found
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transaction_receipt("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// # })
+    /// ```
+    pub async fn get_transaction_receipt(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionReceipt, Error> {
+        let params = rpc_params![normalize_hash(transaction_hash)];
+        self.request("getTransactionReceipt", params).await
+    }
+
+    /// Returns the fee of a transaction in Lunas.
+    /// `Note` Receipts do not contain the fee, so it is read from `get_transaction_by_hash`. Nimiq has no gas: the fee set in the transaction is exactly the fee charged once it is included.
+    ///
+    /// # Arguments
+    ///
+    /// * `String`: Hash of a transaction
+    ///
+    /// # Returns
+    ///
+    /// The fee of the transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.transaction_fee("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// # })
+    /// ```
+    pub async fn transaction_fee(&self, transaction_hash: &str) -> Result<u64, Error> {
+        Ok(self.get_transaction_by_hash(transaction_hash).await?.fee)
     }
 
-    /// Returns a list of addresses owned by client.
+    /// Waits until a transaction is mined and has the given number of confirmations, checking every 5 seconds.
+    /// `Note` This waits indefinitely, wrap it in `tokio::time::timeout` to give up after a while.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `String`: Hash of a transaction, e.g. as returned by `send_transaction`.
+    /// * `Int`: Number of confirmations to wait for.
     ///
     /// # Returns
     ///
-    /// Vector of accounts owned by the client.
+    /// The transaction receipt once the transaction has enough confirmations.
     ///
     /// # Example
     ///
@@ -56,73 +2295,149 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.accounts();
+    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
+    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+    ///    value: 100, //Lunas
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
+    /// };
+    /// if let Ok(hash) = client.send_transaction(&tx).await {
+    ///     let receipt = client.confirm(&hash, 10).await;
+    /// }
     /// # })
     /// ```
-    pub async fn accounts(&self) -> Result<Vec<Account>, Error> {
-        let params = rpc_params![];
-        self.agent.request("accounts", params).await
+    pub async fn confirm(
+        &self,
+        transaction_hash: &str,
+        confirmations: u32,
+    ) -> Result<TransactionReceipt, Error> {
+        loop {
+            let params = rpc_params![normalize_hash(transaction_hash)];
+            let receipt: Option<TransactionReceipt> =
+                self.request("getTransactionReceipt", params).await?;
+            if let Some(receipt) = receipt {
+                if receipt.confirmations >= confirmations {
+                    return Ok(receipt);
+                }
+            }
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        }
     }
 
-    /// Returns the height of most recent block.
+    /// Sends a transaction with `send_transaction` and waits until it shows up in the node's mempool, from where the node relays it to its peers.
+    /// `Note` This confirms that the node accepted the transaction, not that other nodes received it. A transaction that is mined before it is seen in the mempool also times out, so check its receipt with `confirm` after a timeout.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `OutgoingTransaction`: The transaction object
+    /// * `Duration`: How long to wait for the transaction to appear in the mempool.
     ///
     /// # Returns
     ///
-    /// The current block height the client is on.
+    /// The Hex-encoded transaction hash once the transaction is in the mempool, or `NimiqError::Timeout` if it did not appear in time. Fails like `send_transaction` if the transaction cannot be sent.
     ///
     /// # Example
     ///
     /// ```
     /// use nimiq_rpc::Client;
+    /// use std::time::Duration;
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.block_number().await;
+    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
+    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+    ///    value: 100, //Lunas
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
+    /// };
+    /// let result = client.send_and_verify_propagation(&tx, Duration::from_secs(10)).await;
     /// # })
     /// ```
-    pub async fn block_number(&self) -> Result<u32, Error> {
-        let params = rpc_params![];
-        self.agent.request("blockNumber", params).await
+    pub async fn send_and_verify_propagation(
+        &self,
+        transaction: &OutgoingTransaction,
+        timeout: Duration,
+    ) -> Result<String, Error> {
+        let hash = self.send_transaction(transaction).await?;
+        let normalized = normalize_hash(&hash);
+        let propagated = async {
+            loop {
+                let mempool = self.mempool_content().await?;
+                if mempool
+                    .iter()
+                    .any(|pending| normalize_hash(pending) == normalized)
+                {
+                    return Ok::<_, Error>(());
+                }
+                tokio::time::sleep(PROPAGATION_POLL_INTERVAL).await;
+            }
+        };
+        tokio::time::timeout(timeout, propagated)
+            .await
+            .map_err(|_| Error::Timeout(timeout))??;
+        Ok(hash)
     }
 
-    /// Returns information on the current consensus state.
+    /// Waits until the node has established consensus and is no longer syncing, e.g. while a service starts.
+    /// `Note` Errors, such as the node not accepting connections yet, are treated like a node that is not ready, and polling continues until `timeout`.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `Duration`: How long to wait at most.
+    /// * `Duration`: How long to wait between two checks.
     ///
     /// # Returns
     ///
-    /// String describing the consensus state. `"established"` is the value for a good state, other values indicate bad state.
+    /// Nothing once the node is ready, or `NimiqError::Timeout` if it did not become ready in time.
     ///
     /// # Example
     ///
-    /// ```
+    /// ```no_run
     /// use nimiq_rpc::Client;
+    /// use std::time::Duration;
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.consensus().await;
+    /// let result = client.wait_until_ready(Duration::from_secs(300), Duration::from_secs(5)).await;
     /// # })
     /// ```
-    pub async fn consensus(&self) -> Result<String, Error> {
-        let params = rpc_params![];
-        self.agent.request("consensus", params).await
+    pub async fn wait_until_ready(&self, timeout: Duration, poll: Duration) -> Result<(), Error> {
+        let ready = async {
+            loop {
+                let consensus = self.consensus().await;
+                if matches!(consensus.as_deref(), Ok("established"))
+                    && matches!(self.syncing().await, Ok(Syncing::IsSyncing(false)))
+                {
+                    return;
+                }
+                tokio::time::sleep(poll).await;
+            }
+        };
+        tokio::time::timeout(timeout, ready)
+            .await
+            .map_err(|_| Error::Timeout(timeout))
     }
 
-    /// Creates a new account and stores its private key in the client store.
+    /// Returns the latest transactions successfully performed by or for an address.
+    /// `Note` That this information might change when blocks are rewinded on the local state due to forks.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `String`: Address of which transactions should be gathered.
+    /// * `Int`: Number of transactions that shall be returned.
     ///
     /// # Returns
     ///
-    /// Information on the wallet that was created using the command.
+    /// Vector of transactions linked to the requested address, without duplicates and oldest first: transactions by ascending block number, followed by those not yet included in a block. Transactions of the same block are ordered by hash, as `TransactionDetails` carries no index within the block and looking it up with `get_transaction_receipt` would cost a request per transaction.
+    /// `Note` The array will not contain more than the requested amount of transactions, but might contain less, even when more transactions happened. Any interpretation of the length of this array might result in worng assumptions.
+    /// Served from the cache if enabled with `with_history_cache` and the newest transaction of the address is part of the cached history.
+    /// `Note` The node does not report the total number of transactions of an address, so there is no count to page against. Fetching the whole history just to count it would be as expensive as loading it, so the client does not offer such a method.
     ///
     /// # Example
     ///
@@ -131,23 +2446,51 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.create_account().await;
+    /// let result = client.get_transactions_by_address("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 10).await;
     /// # })
     /// ```
-    pub async fn create_account(&self) -> Result<Wallet, Error> {
-        let params = rpc_params![];
-        self.agent.request("createAccount", params).await
+    pub async fn get_transactions_by_address(
+        &self,
+        address: &str,
+        amount: u16,
+    ) -> Result<Vec<TransactionDetails>, Error> {
+        let key = HistoryCache::key(address, amount);
+        let cached = self
+            .history_cache
+            .as_ref()
+            .and_then(|cache| cache.lock().unwrap().get(&key));
+        if let Some(transactions) = cached {
+            let head = self
+                .get_transactions_by_address_unsorted(address, 1)
+                .await?;
+            let unchanged = match head.first() {
+                Some(newest) => transactions.iter().any(|tx| tx.hash == newest.hash),
+                None => transactions.is_empty(),
+            };
+            if unchanged {
+                return Ok(transactions);
+            }
+        }
+        let mut transactions = self
+            .get_transactions_by_address_unsorted(address, amount)
+            .await?;
+        sort_transactions(&mut transactions);
+        if let Some(cache) = &self.history_cache {
+            cache.lock().unwrap().insert(key, transactions.clone());
+        }
+        Ok(transactions)
     }
 
-    /// Creates and signs a transaction without sending it. The transaction can then be send via `sendRawTransaction` without accidentally replaying it.
+    /// Returns the latest transactions of an address exactly as returned by the node, which may list a transaction twice when the address is both sender and recipient, in no guaranteed order.
     ///
     /// # Arguments
     ///
-    /// * `raw_transaction`: `&primitives::OutgoingTransaction`
+    /// * `String`: Address of which transactions should be gathered.
+    /// * `Int`: Number of transactions that shall be returned.
     ///
     /// # Returns
     ///
-    /// The Hex-encoded transaction.
+    /// Vector of transactions linked to the requested address.
     ///
     /// # Example
     ///
@@ -156,84 +2499,131 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
-    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
-    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
-    ///    value: 100, //Lunas
-    ///    fee: 0
-    /// };
-    /// let result = client.create_raw_transaction(&tx).await;
+    /// let result = client.get_transactions_by_address_unsorted("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 10).await;
     /// # })
     /// ```
-    pub async fn create_raw_transaction(
+    pub async fn get_transactions_by_address_unsorted(
         &self,
-        raw_transaction: &OutgoingTransaction,
-    ) -> Result<String, Error> {
-        let params = rpc_params![raw_transaction];
-        self.agent.request("createRawTransaction", params).await
+        address: &str,
+        amount: u16,
+    ) -> Result<Vec<TransactionDetails>, Error> {
+        let params = rpc_params![address, amount];
+        self.request("getTransactionsByAddress", params).await
     }
 
-    /// Returns details for the account of given address.
+    /// Returns the latest transactions of an address, keeping only those sent or received by it.
+    /// `Note` The node has no server-side filter, so filtering happens client-side. When too few transactions match, more are requested from the node until `amount` is reached or the history is exhausted.
     ///
     /// # Arguments
     ///
-    /// * `String`: Address to check for balance.
+    /// * `String`: Address of which transactions should be gathered.
+    /// * `Int`: Number of transactions that shall be returned.
+    /// * `Direction`: Whether to keep sent, received or all transactions.
     ///
     /// # Returns
     ///
-    /// Details about the account. Returns the default empty basic account for non-existing accounts.
+    /// Vector of the latest `amount` transactions matching the direction, oldest first like `get_transactions_by_address`.
     ///
     /// # Example
     ///
     /// ```
-    /// use nimiq_rpc::Client;
+    /// use nimiq_rpc::{primitives::Direction, Client};
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_account("ad25610feb43d75307763d3f010822a757027429").await;
+    /// let result = client.get_transactions_by_address_filtered("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 10, Direction::Sent).await;
     /// # })
     /// ```
-    pub async fn get_account(&self, id: &str) -> Result<Account, Error> {
-        let params = rpc_params![id];
-        self.agent.request("getAccount", params).await
+    pub async fn get_transactions_by_address_filtered(
+        &self,
+        address: &str,
+        amount: u16,
+        direction: Direction,
+    ) -> Result<Vec<TransactionDetails>, Error> {
+        let mut fetch = amount;
+        loop {
+            let transactions = self
+                .get_transactions_by_address_unsorted(address, fetch)
+                .await?;
+            let exhausted = transactions.len() < fetch as usize || fetch == u16::MAX;
+            let mut filtered: Vec<_> = transactions
+                .into_iter()
+                .filter(|tx| direction.matches(tx, address))
+                .collect();
+            sort_transactions(&mut filtered);
+            if filtered.len() >= amount as usize || exhausted {
+                filtered.drain(..filtered.len().saturating_sub(amount as usize));
+                return Ok(filtered);
+            }
+            fetch = fetch.saturating_mul(2);
+        }
     }
 
-    /// Returns an Accounts tree chunk.
+    /// Returns the transactions of an address that were included in a block after the given timestamp, newest first.
+    /// `Note` The node cannot filter by time, so transactions are filtered client-side. More transactions are requested from the node until one at or before `since_timestamp` shows up or the history is exhausted. Transactions not yet included in a block are skipped.
     ///
     /// # Arguments
     ///
-    /// * `String`: Block hash on which the accounts tree chunk is going to be retrieved.
-    /// * `String`: Start prefix for the accounts tree chunk to retrieve.
+    /// * `String`: Address of which transactions should be gathered.
+    /// * `Int`: Timestamp of the last transaction already seen. Only strictly newer transactions are returned.
+    /// * `Int`: Maximum number of transactions that shall be returned.
     ///
     /// # Returns
     ///
-    /// An Accounts tree chunk containing the tail prefix and the length of the chunk.
+    /// Vector of at most `max` transactions newer than `since_timestamp`, newest first.
     ///
     /// # Example
     ///
     /// ```
     /// use nimiq_rpc::Client;
-    /// let client = Client::new("http://seed-host.com:8648".to_string());
-    /// let result = client.get_accounts_tree_chunk("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", "").await;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_transactions_by_address_since("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 1528297445, 10).await;
+    /// # })
     /// ```
-    pub async fn get_accounts_tree_chunk(
+    pub async fn get_transactions_by_address_since(
         &self,
-        block_hash: &str,
-        start_prefix: &str,
-    ) -> Result<AccountsTreeChunk, Error> {
-        let params = rpc_params![block_hash, start_prefix];
-        self.agent.request("getAccountsTreeChunk", params).await
+        address: &str,
+        since_timestamp: u32,
+        max: u16,
+    ) -> Result<Vec<TransactionDetails>, Error> {
+        let mut fetch = max.max(1);
+        loop {
+            let transactions = self
+                .get_transactions_by_address_unsorted(address, fetch)
+                .await?;
+            let exhausted = transactions.len() < fetch as usize || fetch == u16::MAX;
+            let crossed = transactions
+                .iter()
+                .any(|tx| matches!(tx.timestamp, Some(timestamp) if timestamp <= since_timestamp));
+            let mut newer: Vec<_> = transactions
+                .into_iter()
+                .filter(|tx| matches!(tx.timestamp, Some(timestamp) if timestamp > since_timestamp))
+                .collect();
+            if crossed || exhausted || newer.len() >= max as usize {
+                sort_transactions(&mut newer);
+                newer.reverse();
+                newer.truncate(max as usize);
+                return Ok(newer);
+            }
+            fetch = fetch.saturating_mul(2);
+        }
     }
 
-    /// Returns the balance of the account of given address.
+    /// Returns how the balance of an address changed between two blocks.
+    /// `Note` The node keeps no historical balances, so the change is computed by summing the address's transactions included in blocks `from_block + 1 ..= to_block`: received values are added, sent values and fees are subtracted. Block rewards are not transactions and are therefore not included.
     ///
     /// # Arguments
     ///
-    /// * `String`: Address to check for balance.
+    /// * `String`: Address of which the balance change should be computed.
+    /// * `Int`: Height of the block to compare from (exclusive).
+    /// * `Int`: Height of the block to compare to (inclusive).
     ///
     /// # Returns
     ///
-    /// Details about the account. The current balance at the specified address (in smallest unit).
+    /// The signed balance change in Luna, or `NimiqError::InvalidArgument` if `from_block` is greater than `to_block`.
+    /// Fails with `NimiqError::InvalidResponse` if the change does not fit into an `i64`.
     ///
     /// # Example
     ///
@@ -242,24 +2632,65 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_balance("ad25610feb43d75307763d3f010822a757027429").await;
+    /// let result = client.balance_change("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 76415, 882418).await;
     /// # })
     /// ```
-    pub async fn get_balance(&self, id: &str) -> Result<u64, Error> {
-        let params = rpc_params![id];
-        self.agent.request("getBalance", params).await
+    pub async fn balance_change(
+        &self,
+        address: &str,
+        from_block: u32,
+        to_block: u32,
+    ) -> Result<i64, Error> {
+        if from_block > to_block {
+            return Err(Error::InvalidArgument(format!(
+                "Block range start {} is greater than its end {}",
+                from_block, to_block
+            )));
+        }
+        let mut fetch: u16 = 100;
+        let mut transactions = loop {
+            let transactions = self
+                .get_transactions_by_address_unsorted(address, fetch)
+                .await?;
+            // Once a transaction at or before `from_block` shows up, the whole range is covered.
+            let covered = transactions
+                .iter()
+                .filter_map(|tx| tx.block_number)
+                .any(|height| height <= from_block);
+            if covered || transactions.len() < fetch as usize || fetch == u16::MAX {
+                break transactions;
+            }
+            fetch = fetch.saturating_mul(2);
+        };
+        sort_transactions(&mut transactions);
+
+        let overflow = || Error::InvalidResponse("Balance change overflows".to_string());
+        let mut change: i64 = 0;
+        for tx in transactions.iter().filter(
+            |tx| matches!(tx.block_number, Some(height) if height > from_block && height <= to_block),
+        ) {
+            if Direction::Received.matches(tx, address) {
+                let value = i64::try_from(tx.value).map_err(|_| overflow())?;
+                change = change.checked_add(value).ok_or_else(overflow)?;
+            }
+            if Direction::Sent.matches(tx, address) {
+                let spent = tx.value.checked_add(tx.fee).ok_or_else(overflow)?;
+                let spent = i64::try_from(spent).map_err(|_| overflow())?;
+                change = change.checked_sub(spent).ok_or_else(overflow)?;
+            }
+        }
+        Ok(change)
     }
 
-    /// Returns information about a block by hash.
+    /// Returns instructions to mine the next block. This will consider pool instructions when connected to a pool.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of the block to gather information on.
-    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A block object or `null` when no block was found.
+    /// Mining work instructions
     ///
     /// # Example
     ///
@@ -268,49 +2699,43 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_block_by_hash("14c91f6d6f3a0b62271e546bb09461231ab7e4d1ddc2c3e1b93de52d48a1da87", false).await;
+    /// let result = client.get_work().await;
     /// # })
     /// ```
-    pub async fn get_block_by_hash(
-        &self,
-        block_hash: &str,
-        full_transactions: bool,
-    ) -> Result<Block, Error> {
-        let params = rpc_params![block_hash, full_transactions];
-        self.agent.request("getBlockByHash", params).await
+    pub async fn get_work(&self) -> Result<GetWork, Error> {
+        let params = rpc_params![];
+        self.request("getWork", params).await
     }
 
-    /// Returns information about a block by block number.
+    /// Like `get_work`, but gives up after `timeout`, so that a mining loop does not stall on a stuck request.
+    /// `Note` This bounds only this call, independently of the request timeout of the client.
     ///
     /// # Arguments
     ///
-    /// * `Int`: The height of the block to gather information on.
-    /// * `Boolean`: If `true` it returns the full transaction objects, if `false` only the hashes of the transactions.
+    /// * `Duration`: How long to wait for the work at most.
     ///
     /// # Returns
     ///
-    /// A block object or `null` when no block was found.
+    /// Mining work instructions, or `NimiqError::Timeout` if the node did not answer in time.
     ///
     /// # Example
     ///
     /// ```
     /// use nimiq_rpc::Client;
+    /// use std::time::Duration;
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_block_by_number(1234, false).await;
+    /// let result = client.get_work_timeout(Duration::from_secs(2)).await;
     /// # })
     /// ```
-    pub async fn get_block_by_number(
-        &self,
-        block_number: u32,
-        full_transactions: bool,
-    ) -> Result<Block, Error> {
-        let params = rpc_params![block_number, full_transactions];
-        self.agent.request("getBlockByNumber", params).await
+    pub async fn get_work_timeout(&self, timeout: Duration) -> Result<GetWork, Error> {
+        tokio::time::timeout(timeout, self.get_work())
+            .await
+            .map_err(|_| Error::Timeout(timeout))?
     }
 
-    /// Returns a template to build the next block for mining. This will consider pool instructions when connected to a pool.
+    /// Returns the number of hashes per second that the node is mining with.
     ///
     /// # Arguments
     ///
@@ -318,7 +2743,7 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// A block template object.
+    /// Number of hashes per second.
     ///
     /// # Example
     ///
@@ -327,23 +2752,24 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_block_template().await;
+    /// let result = client.hashrate().await;
     /// # })
     /// ```
-    pub async fn get_block_template(&self) -> Result<FullBlock, Error> {
+    pub async fn hashrate(&self) -> Result<f64, Error> {
         let params = rpc_params![];
-        self.agent.request("getBlockTemplate", params).await
+        self.request("hashrate", params).await
     }
 
-    /// Returns the number of transactions in a block from a block matching the given block hash.
+    /// Sets the log level of the node.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of the block.
+    /// * `String`: Tag: If `'*'` the log level is set globally, otherwise the log level is applied only on this tag.
+    /// * `String`: Minimum log level to display. (Valid options: `trace`, `verbose`, `debug`, `info`, `warn`, `error`, `assert`)
     ///
     /// # Returns
     ///
-    /// Number of transactions in the block found, or `null`, when no block was found.
+    /// `true`
     ///
     /// # Example
     ///
@@ -352,28 +2778,23 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_block_transaction_count_by_hash("dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f").await;
+    /// let result = client.log("*", "log").await;
     /// # })
     /// ```
-    pub async fn get_block_transaction_count_by_hash(
-        &self,
-        block_hash: &str,
-    ) -> Result<u16, Error> {
-        let params = rpc_params![block_hash];
-        self.agent
-            .request("getBlockTransactionCountByHash", params)
-            .await
+    pub async fn log(&self, tag: &str, level: &str) -> Result<bool, Error> {
+        let params = rpc_params![tag, level];
+        self.request("log", params).await
     }
 
-    /// Returns the number of transactions in a block matching the given block number.
+    /// Returns information on the current mempool situation.
     ///
     /// # Arguments
     ///
-    /// * `Int`: Height of the block.
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// Number of transactions in the block found, or `null`, when no block was found.
+    /// The number of pending transactions, in total and per fee-per-byte bucket.
     ///
     /// # Example
     ///
@@ -382,29 +2803,28 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_block_transaction_count_by_number(76415).await;
+    /// let result = client.mempool().await;
     /// # })
     /// ```
-    pub async fn get_block_transaction_count_by_number(
-        &self,
-        block_number: u32,
-    ) -> Result<u16, Error> {
-        let params = rpc_params![block_number];
-        self.agent
-            .request("getBlockTransactionCountByNumber", params)
-            .await
+    pub async fn mempool(&self) -> Result<MempoolInfo, Error> {
+        let params = rpc_params![];
+        self.request("mempool", params).await
     }
 
-    /// Returns information about a transaction by block hash and transaction index position.
+    pub async fn mempool_content(&self) -> Result<Vec<String>, Error> {
+        let params = rpc_params![];
+        self.request("mempoolContent", params).await
+    }
+
+    /// Returns the transactions in the node's mempool.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of the block containing the transaction
-    /// * `Int`: Index of the transaction in the block
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A transaction object or `null` when no transaction was found.
+    /// Vector of transactions waiting to be included in a block, without block hash, block number and confirmations.
     ///
     /// # Example
     ///
@@ -413,30 +2833,85 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transaction_by_block_hash_and_index("dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f", 20).await;
+    /// let result = client.mempool_transactions().await;
     /// # })
     /// ```
-    pub async fn get_transaction_by_block_hash_and_index(
+    pub async fn mempool_transactions(&self) -> Result<Vec<TransactionDetails>, Error> {
+        let params = rpc_params![true];
+        self.request("mempoolContent", params).await
+    }
+
+    /// Watches the node's mempool by polling `mempool_transactions` every `poll` and yields every transaction that was not in the mempool at the previous poll.
+    /// `Note` The first poll yields all transactions currently in the mempool. Only the hashes of the last poll are remembered, so a transaction that leaves the mempool and comes back is yielded again.
+    ///
+    /// # Arguments
+    ///
+    /// * `Duration`: Time between two polls.
+    ///
+    /// # Returns
+    ///
+    /// A stream of new mempool transactions that never ends. A failed poll yields the error, and polling continues afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use std::time::Duration;
+    /// use url::Url;
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let transactions = client.mempool_stream(Duration::from_secs(1));
+    /// ```
+    pub fn mempool_stream(
         &self,
-        block_hash: &str,
-        index: u16,
-    ) -> Result<Transaction, Error> {
-        let params = rpc_params![block_hash, index];
-        self.agent
-            .request("getTransactionByBlockHashAndIndex", params)
-            .await
+        poll: Duration,
+    ) -> impl Stream<Item = Result<TransactionDetails, Error>> + Send + 'static {
+        struct State {
+            client: Client,
+            seen: HashSet<String>,
+            new: VecDeque<TransactionDetails>,
+            polled: bool,
+        }
+
+        let state = State {
+            client: self.clone(),
+            seen: HashSet::new(),
+            new: VecDeque::new(),
+            polled: false,
+        };
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(transaction) = state.new.pop_front() {
+                    return Some((Ok(transaction), state));
+                }
+                if state.polled {
+                    tokio::time::sleep(poll).await;
+                }
+                state.polled = true;
+                let transactions = match state.client.mempool_transactions().await {
+                    Ok(transactions) => transactions,
+                    Err(error) => return Some((Err(error), state)),
+                };
+                let seen = transactions.iter().map(|tx| tx.hash.clone()).collect();
+                state.new.extend(
+                    transactions
+                        .into_iter()
+                        .filter(|tx| !state.seen.contains(&tx.hash)),
+                );
+                // Hashes that left the mempool are forgotten.
+                state.seen = seen;
+            }
+        })
     }
 
-    /// Returns information about a transaction by block number and transaction index position.
+    /// Returns the hashes of the transactions in the node's mempool, like `mempool_content`, but parsed into [`Hash`](struct@Hash)es.
     ///
     /// # Arguments
     ///
-    /// * `Int`: Height of the block containing the transaction
-    /// * `Int`: Index of the transaction in the block
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A transaction object or `null` when no transaction was found.
+    /// Vector of transaction hashes. Fails with `NimiqError::InvalidResponse` if the node returns a malformed hash.
     ///
     /// # Example
     ///
@@ -445,29 +2920,54 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transaction_by_block_number_and_index(76415, 20).await;
+    /// let result = client.mempool_hashes().await;
     /// # })
     /// ```
-    pub async fn get_transaction_by_block_number_and_index(
-        &self,
-        block_number: u32,
-        index: u16,
-    ) -> Result<Transaction, Error> {
-        let params = rpc_params![block_number, index];
-        self.agent
-            .request("getTransactionByBlockNumberAndIndex", params)
-            .await
+    pub async fn mempool_hashes(&self) -> Result<Vec<Hash>, Error> {
+        self.mempool_content()
+            .await?
+            .iter()
+            .map(|hash| {
+                hash.parse()
+                    .map_err(|e| Error::InvalidResponse(format!("Invalid hash `{}`: {}", hash, e)))
+            })
+            .collect()
+    }
+
+    pub async fn miner_address(&self) -> Result<String, Error> {
+        let params = rpc_params![];
+        self.request("minerAddress", params).await
+    }
+
+    pub async fn miner_threads(&self) -> Result<u8, Error> {
+        let params = rpc_params![];
+        self.request("minerThreads", params).await
+    }
+
+    pub async fn miner_threads_with_update(&self, threads: u16) -> Result<u16, Error> {
+        let params = rpc_params![threads];
+        self.request("minerThreads", params).await
+    }
+
+    pub async fn min_fee_per_byte(&self) -> Result<u32, Error> {
+        let params = rpc_params![];
+        self.request("minFeePerByte", params).await
+    }
+
+    pub async fn min_fee_per_byte_with_update(&self, fee: u32) -> Result<u32, Error> {
+        let params = rpc_params![fee];
+        self.request("minFeePerByte", params).await
     }
 
-    /// Returns the information about a transaction requested by transaction hash.
+    /// Returns `true` if client is actively mining new blocks.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of a transaction
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A transaction object or `null` when no transaction was found.
+    /// Returns `true` if the client is mining, otherwise `false`.
     ///
     /// # Example
     ///
@@ -476,26 +2976,23 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transaction_by_hash("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// let result = client.mining().await;
     /// # })
     /// ```
-    pub async fn get_transaction_by_hash(
-        &self,
-        transaction_hash: &str,
-    ) -> Result<TransactionDetails, Error> {
-        let params = rpc_params![transaction_hash];
-        self.agent.request("getTransactionByHash", params).await
+    pub async fn mining(&self) -> Result<bool, Error> {
+        let params = rpc_params![];
+        self.request("mining", params).await
     }
 
-    /// Returns the information about a transaction requested by transaction hash.
+    /// Returns number of peers currently connected to the client.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of a transaction
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A transaction object or `null` when no transaction was found.
+    /// Integer of the number of connected peers.
     ///
     /// # Example
     ///
@@ -504,27 +3001,24 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transaction_by_hash_2("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// let result = client.peer_count().await;
     /// # })
     /// ```
-    pub async fn get_transaction_by_hash_2(
-        &self,
-        transaction_hash: &str,
-    ) -> Result<TransactionDetails2, Error> {
-        let params = rpc_params![transaction_hash];
-        self.agent.request("getTransactionByHash2", params).await
+    pub async fn peer_count(&self) -> Result<i8, Error> {
+        let params = rpc_params![];
+        self.request("peerCount", params).await
     }
 
-    /// Returns the receipt of a transaction by transaction hash.
-    /// `Note` That the receipt is not available for pending transactions.
+    /// Returns the list of peers known to the node.
+    /// `Note` The `version` of each peer is the protocol version it announced. The JSON-RPC API has no method returning the software version of the queried node itself, so the client cannot tell which node version it talks to.
     ///
     /// # Arguments
     ///
-    /// * `String`: Hash of a transaction
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// A transaction receipt object, or `null` when no receipt was found
+    /// Array of peer objects, with their address, connection state, protocol version and head hash.
     ///
     /// # Example
     ///
@@ -533,58 +3027,82 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transaction_receipt("465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554").await;
+    /// let result = client.peer_list().await;
     /// # })
     /// ```
-    pub async fn get_transaction_receipt(
-        &self,
-        transaction_hash: &str,
-    ) -> Result<TransactionReceipt, Error> {
-        let params = rpc_params![transaction_hash];
-        self.agent.request("getTransactionReceipt", params).await
+    pub async fn peer_list(&self) -> Result<Vec<PeerList>, Error> {
+        let params = rpc_params![];
+        self.request("peerList", params).await
     }
 
-    /// Returns the latest transactions successfully performed by or for an address.
-    /// `Note` That this information might change when blocks are rewinded on the local state due to forks.
+    /// Watches the node's peers by polling `peer_list` every `poll` and yields the list whenever a peer connects, disconnects or changes its connection state.
+    /// `Note` This is polling-based, so changes are detected at most once per `poll`, and a change that is undone before the next poll goes unnoticed.
     ///
     /// # Arguments
     ///
-    /// * `String`: Address of which transactions should be gathered.
-    /// * `Int`: Number of transactions that shall be returned.
+    /// * `Duration`: Time between two polls.
     ///
     /// # Returns
     ///
-    /// Vector of transactions linked to the requested address.
-    /// `Note` The array will not contain more than the requested amount of transactions, but might contain less, even when more transactions happened. Any interpretation of the length of this array might result in worng assumptions.
+    /// A stream of peer lists that never ends, starting with the current list. A failed poll yields the error, and polling continues afterwards.
     ///
     /// # Example
     ///
     /// ```
     /// use nimiq_rpc::Client;
+    /// use std::time::Duration;
     /// use url::Url;
-    /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_transactions_by_address("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 10).await;
-    /// # })
+    /// let peers = client.peer_list_stream(Duration::from_secs(10));
     /// ```
-    pub async fn get_transactions_by_address(
+    pub fn peer_list_stream(
         &self,
-        address: &str,
-        amount: u16,
-    ) -> Result<Vec<TransactionDetails>, Error> {
-        let params = rpc_params![address, amount];
-        self.agent.request("getTransactionsByAddress", params).await
+        poll: Duration,
+    ) -> impl Stream<Item = Result<Vec<PeerList>, Error>> + Send + 'static {
+        let client = self.clone();
+        // Ids and connection states of the last yielded list, `None` before the first one.
+        let last: Option<Vec<(String, Option<u64>)>> = None;
+        let state = (client, last, false);
+        stream::unfold(state, move |(client, last, polled)| async move {
+            if polled {
+                tokio::time::sleep(poll).await;
+            }
+            loop {
+                let peers = match client.peer_list().await {
+                    Ok(peers) => peers,
+                    Err(error) => return Some((Err(error), (client, last, true))),
+                };
+                let mut states: Vec<_> = peers
+                    .iter()
+                    .map(|peer| (peer.id.clone(), peer.connection_state))
+                    .collect();
+                states.sort();
+                if last.as_ref() != Some(&states) {
+                    return Some((Ok(peers), (client, Some(states), true)));
+                }
+                tokio::time::sleep(poll).await;
+            }
+        })
     }
 
-    /// Returns instructions to mine the next block. This will consider pool instructions when connected to a pool.
+    /// Returns the state of a peer.
+    /// `Note` The peer address is checked before sending the request: it must be a WebSocket URI with host, port and peer id, e.g. `wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8`. Other addresses fail with `NimiqError::InvalidArgument`.
+    pub async fn peer_state(&self, peer_address: &str) -> Result<PeerState, Error> {
+        validate_peer_address(peer_address)?;
+        let params = rpc_params![peer_address];
+        self.request("peerState", params).await
+    }
+
+    /// Returns the full record of a single peer, as listed by `peer_list`.
+    /// `Note` The node has no method for a single peer's details, so this fetches the whole `peer_list` and picks the entry with the given address. The peer address is checked like in `peer_state`.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `String`: Address of the peer, e.g. `wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8`.
     ///
     /// # Returns
     ///
-    /// Mining work instructions
+    /// The peer's entry of the peer list or `None` when the node does not know the peer.
     ///
     /// # Example
     ///
@@ -593,15 +3111,40 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.get_work().await;
+    /// let result = client
+    ///     .peer_info("wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8")
+    ///     .await;
     /// # })
     /// ```
-    pub async fn get_work(&self) -> Result<GetWork, Error> {
+    pub async fn peer_info(&self, peer_address: &str) -> Result<Option<PeerList>, Error> {
+        validate_peer_address(peer_address)?;
+        let peers = self.peer_list().await?;
+        Ok(peers.into_iter().find(|peer| peer.address == peer_address))
+    }
+
+    /// Changes the state of a peer, e.g. with `connect` or `ban`, and returns its new state.
+    /// `Note` The peer address is checked like in `peer_state`.
+    pub async fn peer_state_with_update(
+        &self,
+        peer_address: &str,
+        set: &str,
+    ) -> Result<PeerState, Error> {
+        validate_peer_address(peer_address)?;
+        let params = rpc_params![peer_address, set];
+        self.request("peerState", params).await
+    }
+
+    pub async fn pool_confirmed_balance(&self) -> Result<u64, Error> {
         let params = rpc_params![];
-        self.agent.request("getWork", params).await
+        self.request("poolConfirmedBalance", params).await
     }
 
-    /// Returns the number of hashes per second that the node is mining with.
+    pub async fn pool_connection_state(&self) -> Result<u8, Error> {
+        let params = rpc_params![];
+        self.request("poolConnectionState", params).await
+    }
+
+    /// Returns a snapshot of the node's state, gathered in a single batch request.
     ///
     /// # Arguments
     ///
@@ -609,7 +3152,7 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// Number of hashes per second.
+    /// The block height, peer count, consensus state, sync status and hashrate of the node.
     ///
     /// # Example
     ///
@@ -618,24 +3161,55 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.hashrate().await;
+    /// let result = client.node_status().await;
     /// # })
     /// ```
-    pub async fn hashrate(&self) -> Result<f64, Error> {
-        let params = rpc_params![];
-        self.agent.request("hashrate", params).await
+    pub async fn node_status(&self) -> Result<NodeStatus, Error> {
+        let methods = [
+            "blockNumber",
+            "peerCount",
+            "consensus",
+            "syncing",
+            "hashrate",
+        ];
+        let mut batch = BatchRequestBuilder::new();
+        for method in methods {
+            batch.insert(method, rpc_params![])?;
+        }
+        self.throttle().await;
+        let values = self
+            .batch_request::<Value>(batch)
+            .await?
+            .into_iter()
+            .zip(methods)
+            .map(|(entry, method)| {
+                entry.map_err(|e| Error::from_call(method, ClientError::Call(e.into_owned())))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let [block_number, peer_count, consensus, syncing, hashrate]: [Value; 5] = values
+            .try_into()
+            .map_err(|_| Error::InvalidResponse("Incomplete batch response".to_string()))?;
+        let block_number = serde_json::from_value(block_number)?;
+        self.observe_block_number(block_number);
+        Ok(NodeStatus {
+            block_number,
+            peer_count: serde_json::from_value(peer_count)?,
+            consensus: serde_json::from_value(consensus)?,
+            syncing: serde_json::from_value(syncing)?,
+            hashrate: serde_json::from_value(hashrate)?,
+        })
     }
 
-    /// Sets the log level of the node.
+    /// Checks whether the node is usable, e.g. for liveness and readiness probes. Never fails: if the node
+    /// cannot be reached or single checks fail, the report says so and lists the errors.
     ///
     /// # Arguments
     ///
-    /// * `String`: Tag: If `'*'` the log level is set globally, otherwise the log level is applied only on this tag.
-    /// * `String`: Minimum log level to display. (Valid options: `trace`, `verbose`, `debug`, `info`, `warn`, `error`, `assert`)
+    /// * `none`
     ///
     /// # Returns
     ///
-    /// `true`
+    /// A report on reachability, consensus, sync status, peer count and latency, gathered in a single batch request.
     ///
     /// # Example
     ///
@@ -644,53 +3218,71 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.log("*", "log").await;
+    /// let report = client.health().await;
     /// # })
     /// ```
-    pub async fn log(&self, tag: &str, level: &str) -> Result<bool, Error> {
-        let params = rpc_params![tag, level];
-        self.agent.request("log", params).await
-    }
-
-    pub async fn mempool_content(&self) -> Result<Vec<String>, Error> {
-        let params = rpc_params![];
-        self.agent.request("mempoolContent", params).await
-    }
-
-    pub async fn miner_address(&self) -> Result<String, Error> {
-        let params = rpc_params![];
-        self.agent.request("minerAddress", params).await
-    }
-
-    pub async fn miner_threads(&self) -> Result<u8, Error> {
-        let params = rpc_params![];
-        self.agent.request("minerThreads", params).await
-    }
-
-    pub async fn miner_threads_with_update(&self, threads: u16) -> Result<u16, Error> {
-        let params = rpc_params![threads];
-        self.agent.request("minerThreads", params).await
-    }
-
-    pub async fn min_fee_per_byte(&self) -> Result<u32, Error> {
-        let params = rpc_params![];
-        self.agent.request("minFeePerByte", params).await
-    }
+    pub async fn health(&self) -> HealthReport {
+        let mut report = HealthReport {
+            reachable: false,
+            consensus_established: false,
+            syncing: true,
+            peer_count: None,
+            latency: None,
+            errors: Vec::new(),
+        };
+        let methods = ["consensus", "syncing", "peerCount"];
+        let mut batch = BatchRequestBuilder::new();
+        for method in methods {
+            if let Err(e) = batch.insert(method, rpc_params![]) {
+                report.errors.push(e.to_string());
+                return report;
+            }
+        }
+        self.throttle().await;
+        let start = Instant::now();
+        let entries = match self.batch_request::<Value>(batch).await {
+            Ok(response) => response.into_iter().collect::<Vec<_>>(),
+            Err(e) => {
+                report.errors.push(e.to_string());
+                return report;
+            }
+        };
+        report.reachable = true;
+        report.latency = Some(start.elapsed());
 
-    pub async fn min_fee_per_byte_with_update(&self, fee: u32) -> Result<u32, Error> {
-        let params = rpc_params![fee];
-        self.agent.request("minFeePerByte", params).await
+        for (entry, method) in entries.into_iter().zip(methods) {
+            let value = match entry {
+                Ok(value) => value,
+                Err(e) => {
+                    report.errors.push(format!("{}: {}", method, e.message()));
+                    continue;
+                }
+            };
+            let parsed = match method {
+                "consensus" => serde_json::from_value::<String>(value)
+                    .map(|consensus| report.consensus_established = consensus == "established"),
+                "syncing" => serde_json::from_value::<Syncing>(value)
+                    .map(|syncing| report.syncing = !matches!(syncing, Syncing::IsSyncing(false))),
+                _ => serde_json::from_value::<u32>(value)
+                    .map(|peer_count| report.peer_count = Some(peer_count)),
+            };
+            if let Err(e) = parsed {
+                report.errors.push(format!("{}: {}", method, e));
+            }
+        }
+        report
     }
 
-    /// Returns `true` if client is actively mining new blocks.
+    /// Creates and signs a transaction without sending it, and returns the details of the signed transaction.
+    /// This allows to confirm what will be sent before calling `send_raw_transaction`.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `OutgoingTransaction`: The transaction object
     ///
     /// # Returns
     ///
-    /// Returns `true` if the client is mining, otherwise `false`.
+    /// A transaction object as the node would see it.
     ///
     /// # Example
     ///
@@ -699,23 +3291,39 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.mining().await;
+    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
+    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+    ///    value: 100, //Lunas
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
+    /// };
+    /// let result = client.preview_transaction(&tx).await;
     /// # })
     /// ```
-    pub async fn mining(&self) -> Result<bool, Error> {
-        let params = rpc_params![];
-        self.agent.request("mining", params).await
+    pub async fn preview_transaction(
+        &self,
+        transaction: &OutgoingTransaction,
+    ) -> Result<TransactionDetails2, Error> {
+        let raw_transaction = self.create_raw_transaction(transaction).await?;
+        self.get_raw_transaction_info(&raw_transaction).await
     }
 
-    /// Returns number of peers currently connected to the client.
+    /// Builds a transaction with the validity start height and network id the node would sign it with.
+    /// `Note` Only the block height and the network id are requested from the node, so this works for any sender, including watch-only addresses, and signs nothing.
     ///
     /// # Arguments
     ///
-    /// * `none`
+    /// * `String`: Address of the sender.
+    /// * `String`: Address of the recipient.
+    /// * `Int`: Value in Lunas.
+    /// * `Int`: Fee in Lunas.
     ///
     /// # Returns
     ///
-    /// Integer of the number of connected peers.
+    /// A transaction with `validity_start_height` set to the current block height and `network_id` set to the network of the node, ready to be sent with `send_transaction`.
     ///
     /// # Example
     ///
@@ -724,41 +3332,25 @@ impl Client {
     /// use url::Url;
     /// # tokio_test::block_on(async {
     /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
-    /// let result = client.peer_count().await;
+    /// let result = client.prepare_transaction("NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42", "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3", 100, 0).await;
     /// # })
     /// ```
-    pub async fn peer_count(&self) -> Result<i8, Error> {
-        let params = rpc_params![];
-        self.agent.request("peerCount", params).await
-    }
-
-    pub async fn peer_list(&self) -> Result<Vec<PeerList>, Error> {
-        let params = rpc_params![];
-        self.agent.request("peerList", params).await
-    }
-
-    pub async fn peer_state(&self, peer_address: &str) -> Result<PeerState, Error> {
-        let params = rpc_params![peer_address];
-        self.agent.request("peerState", params).await
-    }
-
-    pub async fn peer_state_with_update(
+    pub async fn prepare_transaction(
         &self,
-        peer_address: &str,
-        set: &str,
-    ) -> Result<PeerState, Error> {
-        let params = rpc_params![peer_address, set];
-        self.agent.request("peerState", params).await
-    }
-
-    pub async fn pool_confirmed_balance(&self) -> Result<u64, Error> {
-        let params = rpc_params![];
-        self.agent.request("poolConfirmedBalance", params).await
-    }
-
-    pub async fn pool_connection_state(&self) -> Result<u8, Error> {
-        let params = rpc_params![];
-        self.agent.request("poolConnectionState", params).await
+        from: &str,
+        to: &str,
+        value: u64,
+        fee: u64,
+    ) -> Result<OutgoingTransaction, Error> {
+        Ok(OutgoingTransaction {
+            from: from.to_string(),
+            to: to.to_string(),
+            value,
+            fee,
+            data: None,
+            validity_start_height: Some(self.block_number().await?),
+            network_id: Some(self.get_network_id().await?),
+        })
     }
 
     /// Sends a signed message call transaction or a contract creation, if the data field contains code.
@@ -769,7 +3361,8 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// The Hex-encoded transaction hash.
+    /// The Hex-encoded transaction hash. Fails with `NimiqError::InvalidHex` without contacting the node if the transaction is not valid hex.
+    /// `Note` Sending the same signed transaction again, e.g. after a timeout, is safe: it has the same hash and is included at most once. Signing the transaction again for a retry is not, as it may get a different validity start height and become a second transaction. `create_and_send` takes care of this.
     ///
     /// # Example
     ///
@@ -782,18 +3375,24 @@ impl Client {
     ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
     ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
     ///    value: 100, //Lunas
-    ///    fee: 0
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
     /// };
-    /// let result = client.create_raw_transaction(&tx).await;
-    /// let hash = client.send_raw_transaction(&result.unwrap()).await;
+    /// if let Ok(raw_transaction) = client.create_raw_transaction(&tx).await {
+    ///     let hash = client.send_raw_transaction(&raw_transaction).await;
+    /// }
     /// # })
     /// ```
     pub async fn send_raw_transaction(&self, transaction_hash: &str) -> Result<String, Error> {
+        validate_hex(transaction_hash)?;
         let params = rpc_params![transaction_hash];
-        self.agent.request("sendRawTransaction", params).await
+        self.request("sendRawTransaction", params).await
     }
 
     /// Creates new message call transaction or a contract creation, if the data field contains code.
+    /// `Note` There are no nonces in Nimiq, see [`OutgoingTransaction`] for how to send several transactions from one address.
     ///
     /// # Arguments
     ///
@@ -801,7 +3400,8 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// The Hex-encoded transaction hash.
+    /// The Hex-encoded transaction hash, or `NimiqError::NetworkMismatch` without sending the transaction if its `network_id` differs from the network of the node.
+    /// Fails with `NimiqError::ValidityStartOutOfWindow` without sending the transaction if its `validity_start_height` is set and the next block is outside its validity window.
     ///
     /// # Example
     ///
@@ -814,7 +3414,10 @@ impl Client {
     ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
     ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
     ///    value: 100, //Lunas
-    ///    fee: 0
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
     /// };
     /// let result = client.send_transaction(&tx).await;
     /// # })
@@ -823,8 +3426,120 @@ impl Client {
         &self,
         transaction: &OutgoingTransaction,
     ) -> Result<String, Error> {
+        self.check_network(transaction).await?;
+        self.check_validity_start(transaction).await?;
         let params = rpc_params![transaction];
-        self.agent.request("sendTransaction", params).await
+        self.request("sendTransaction", params).await
+    }
+
+    /// Signs a transaction with `create_raw_transaction` and sends it with `send_raw_transaction`, so that retries cannot send it twice.
+    /// `Note` The signed transaction is remembered by the client (and its clones) under `idempotency_key` until it expires. Calling this again with the same key, e.g. to retry after an error, sends the same signed transaction again, which the node includes at most once. Use a new key, such as the id of an order or invoice, for every payment, including repeated payments with the same value and recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `OutgoingTransaction`: The transaction object
+    /// * `String`: Key identifying the payment, the same for all attempts to send it.
+    ///
+    /// # Returns
+    ///
+    /// The Hex-encoded transaction hash, or `NimiqError::NetworkMismatch` without signing the transaction if its `network_id` differs from the network of the node.
+    /// Fails with `NimiqError::ValidityStartOutOfWindow` without signing the transaction if its `validity_start_height` is set and the next block is outside its validity window,
+    /// and with `NimiqError::InvalidArgument` if `idempotency_key` was already used for a different transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let tx = nimiq_rpc::primitives::OutgoingTransaction {
+    ///    from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+    ///    to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+    ///    value: 100, //Lunas
+    ///    fee: 0,
+    ///    data: None,
+    ///    validity_start_height: None,
+    ///    network_id: None,
+    /// };
+    /// let result = client.create_and_send(&tx, "invoice-1234").await;
+    /// # })
+    /// ```
+    pub async fn create_and_send(
+        &self,
+        transaction: &OutgoingTransaction,
+        idempotency_key: &str,
+    ) -> Result<String, Error> {
+        let slot = {
+            let mut slots = self.signed_transactions.lock().unwrap();
+            slots.retain(|_, (_, created)| created.elapsed() < SIGNED_TRANSACTION_LIFETIME);
+            slots
+                .entry(idempotency_key.to_string())
+                .or_insert_with(|| (SignedSlot::default(), Instant::now()))
+                .0
+                .clone()
+        };
+        let raw_transaction = {
+            // Only calls with the same key wait for each other.
+            let mut signed = slot.lock().await;
+            match &*signed {
+                Some((signed_transaction, _)) if signed_transaction != transaction => {
+                    return Err(Error::InvalidArgument(format!(
+                        "Idempotency key `{}` was already used for another transaction",
+                        idempotency_key
+                    )));
+                }
+                Some((_, raw_transaction)) => raw_transaction.clone(),
+                None => {
+                    self.check_network(transaction).await?;
+                    self.check_validity_start(transaction).await?;
+                    let raw_transaction = self.create_raw_transaction(transaction).await?;
+                    *signed = Some((transaction.clone(), raw_transaction.clone()));
+                    raw_transaction
+                }
+            }
+        };
+        self.send_raw_transaction(&raw_transaction).await
+    }
+
+    /// Fails with `NimiqError::NetworkMismatch` if the transaction is meant for another network than the node's.
+    async fn check_network(&self, transaction: &OutgoingTransaction) -> Result<(), Error> {
+        if let Some(network) = transaction.network_id {
+            let node = self.get_network_id().await?;
+            if node != network {
+                return Err(Error::NetworkMismatch {
+                    transaction: network,
+                    node,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails with `NimiqError::ValidityStartOutOfWindow` if the transaction cannot be included in the next block.
+    /// The window is read from the node's `Policy.TRANSACTION_VALIDITY_WINDOW`, falling back to [`TRANSACTION_VALIDITY_WINDOW`].
+    async fn check_validity_start(&self, transaction: &OutgoingTransaction) -> Result<(), Error> {
+        if let Some(validity_start_height) = transaction.validity_start_height {
+            let window = match self
+                .get_constant("Policy.TRANSACTION_VALIDITY_WINDOW")
+                .await
+            {
+                Ok(window) => u32::try_from(window).unwrap_or(TRANSACTION_VALIDITY_WINDOW),
+                Err(_) => TRANSACTION_VALIDITY_WINDOW,
+            };
+            let block_number = self.block_number().await?;
+            // The node accepts a transaction if the next block lies within its validity window.
+            let next = u64::from(block_number) + 1;
+            let start = u64::from(validity_start_height);
+            if next < start || next >= start + u64::from(window) {
+                return Err(Error::ValidityStartOutOfWindow {
+                    validity_start_height,
+                    block_number,
+                    window,
+                });
+            }
+        }
+        Ok(())
     }
 
     /// Submits a block to the node. When the block is valid, the node will forward it to other nodes in the network.
@@ -835,7 +3550,7 @@ impl Client {
     ///
     /// # Returns
     ///
-    /// Nothing
+    /// Nothing. Fails with `NimiqError::InvalidHex` without contacting the node if the block is not valid hex.
     ///
     /// # Example
     ///
@@ -848,8 +3563,79 @@ impl Client {
     /// # })
     /// ```
     pub async fn submit_block(&self, full_block: &str) -> Result<(), Error> {
+        validate_hex(full_block)?;
         let params = rpc_params![full_block];
-        self.agent.request("submitBlock", params).await
+        self.request("submitBlock", params).await
+    }
+
+    /// Submits a sequence of blocks to the node, one after the other.
+    /// `Note` Blocks are submitted sequentially in the given order and each submission is awaited before the next one is sent, since a block usually builds on the previous one. A block rejected by the node does not stop the remaining blocks from being submitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `Array<String>`: Hex-encoded full blocks, in the order they should be submitted.
+    ///
+    /// # Returns
+    ///
+    /// One result per block, in the same order. Fails as a whole only if the node cannot be reached or does not support `submitBlock`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.submit_blocks(&["0da1....234".to_string(), "0da1....567".to_string()]).await;
+    /// # })
+    /// ```
+    pub async fn submit_blocks(&self, blocks: &[String]) -> Result<Vec<Result<(), Error>>, Error> {
+        let mut results = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            match self.submit_block(block).await {
+                Ok(()) => results.push(Ok(())),
+                Err(error @ (Error::Rpc(ClientError::Call(_)) | Error::InvalidHex { .. })) => {
+                    results.push(Err(error))
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Fetches work with `getWork`, searches nonces `0..=max_nonce` on the CPU and submits the first block that meets the target.
+    /// `Note` This is a single-threaded reference implementation for demos and test networks, not a production miner.
+    ///
+    /// # Arguments
+    ///
+    /// * `Int`: The highest nonce to try before giving up.
+    ///
+    /// # Returns
+    ///
+    /// The Hex-encoded hash of the submitted block, or `None` when no nonce up to `max_nonce` met the target.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.mine_once(1000).await;
+    /// # })
+    /// ```
+    pub async fn mine_once(&self, max_nonce: u32) -> Result<Option<String>, Error> {
+        let work = self.get_work().await?;
+        let mut header = work_header(&work)?;
+        let target = work.pool_target();
+
+        if mining::find_nonce(&mut header, &target, 0..=max_nonce).is_none() {
+            return Ok(None);
+        }
+        let mut block = hex::encode(&header);
+        block.push_str(&work.suffix);
+        self.submit_block(&block).await?;
+        Ok(Some(hex::encode(mining::block_hash(&header))))
     }
 
     /// Returns an object with data about the sync status or `false`.
@@ -874,7 +3660,7 @@ impl Client {
     /// ```
     pub async fn syncing(&self) -> Result<Syncing, Error> {
         let params = rpc_params![];
-        self.agent.request("syncing", params).await
+        self.request("syncing", params).await
     }
 
     /// Returns a constant
@@ -899,7 +3685,35 @@ impl Client {
     /// ```
     pub async fn get_constant(&self, constant: &str) -> Result<u64, Error> {
         let params = rpc_params![constant];
-        self.agent.request("constant", params).await
+        self.request("constant", params).await
+    }
+
+    /// Returns the network of the node.
+    /// `Note` There is no dedicated RPC method, so the id is read from the `GenesisConfig.NETWORK_ID` constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `none`
+    ///
+    /// # Returns
+    ///
+    /// The network the node signs transactions for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::Client;
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+    /// let result = client.get_network_id().await;
+    /// # })
+    /// ```
+    pub async fn get_network_id(&self) -> Result<Network, Error> {
+        let id = self.get_constant("GenesisConfig.NETWORK_ID").await?;
+        let id = u8::try_from(id)
+            .map_err(|_| Error::InvalidResponse(format!("Invalid network id: {}", id)))?;
+        Ok(Network::from(id))
     }
 
     /// Sets a constant with the value provided and returns the new value on success
@@ -925,7 +3739,7 @@ impl Client {
     /// ```
     pub async fn set_constant(&self, constant: &str, value: u64) -> Result<u64, Error> {
         let params = rpc_params![constant, value];
-        self.agent.request("constant", params).await
+        self.request("constant", params).await
     }
 
     /// Resets a constant and returns the new value on success
@@ -950,6 +3764,6 @@ impl Client {
     /// ```
     pub async fn reset_constant(&self, constant: &str) -> Result<u64, Error> {
         let params = rpc_params![constant, "reset"];
-        self.agent.request("constant", params).await
+        self.request("constant", params).await
     }
 }
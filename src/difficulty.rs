@@ -0,0 +1,72 @@
+//! Conversions between the compact target (`nBits`), the 256-bit target and the difficulty of a
+//! proof-of-work block.
+//!
+//! Targets are big-endian, like the proof-of-work hashes they are compared to. Difficulty 1 is the
+//! easiest target allowed by the protocol, 2^240, so the difficulty is `2^240 / target`.
+
+/// The compact target of difficulty 1, which expands to 2^240.
+pub const MAX_TARGET_NBITS: u32 = 0x1f01_0000;
+
+/// 2^240, the target of difficulty 1.
+const MAX_TARGET: f64 = 1766847064778384329583297500742918515827483896875618958121606201292619776.0;
+
+/// Expands a compact target (`nBits`) into its 256-bit big-endian representation.
+/// Mantissa bytes that would fall below the last byte of the target are dropped.
+pub fn nbits_to_target(nbits: u32) -> [u8; 32] {
+    let mut target = [0u8; 32];
+    let exponent = (nbits >> 24) as usize;
+    let mantissa = (nbits & 0x00ff_ffff).to_be_bytes();
+    for (i, byte) in mantissa[1..].iter().enumerate() {
+        // Byte `i` of the mantissa lands `exponent - 1 - i` bytes from the right.
+        if exponent > i && exponent - i <= 32 {
+            target[32 + i - exponent] = *byte;
+        }
+    }
+    target
+}
+
+/// Compresses a 256-bit big-endian target into its compact form (`nBits`), keeping the three most
+/// significant bytes.
+pub fn target_to_nbits(target: &[u8; 32]) -> u32 {
+    let Some(first) = target.iter().position(|byte| *byte != 0) else {
+        return 0;
+    };
+    let mut size = (32 - first) as u32;
+    let mut mantissa = target[first..]
+        .iter()
+        .chain([0, 0].iter())
+        .take(3)
+        .fold(0u32, |mantissa, byte| mantissa << 8 | u32::from(*byte));
+    // The mantissa is signed in the compact form, so a set high bit takes one more byte.
+    if mantissa & 0x0080_0000 != 0 {
+        mantissa >>= 8;
+        size += 1;
+    }
+    size << 24 | mantissa
+}
+
+/// Returns the difficulty of a 256-bit big-endian target, `f64::INFINITY` for a zero target.
+pub fn target_to_difficulty(target: &[u8; 32]) -> f64 {
+    let target = target
+        .iter()
+        .fold(0f64, |value, byte| value * 256.0 + f64::from(*byte));
+    MAX_TARGET / target
+}
+
+/// Returns the 256-bit big-endian target of a difficulty, rounded down. Difficulties so low that the
+/// target would not fit into 256 bits give the largest possible target.
+pub fn difficulty_to_target(difficulty: f64) -> [u8; 32] {
+    let mut rest = MAX_TARGET / difficulty;
+    if rest.is_nan() || rest >= 2f64.powi(256) {
+        return [0xff; 32];
+    }
+    let mut target = [0u8; 32];
+    for (i, byte) in target.iter_mut().enumerate() {
+        // Powers of two, so the division and subtraction are exact.
+        let scale = 256f64.powi(31 - i as i32);
+        let value = (rest / scale).floor();
+        *byte = value as u8;
+        rest -= value * scale;
+    }
+    target
+}
@@ -0,0 +1,118 @@
+use std::{fmt, time::Duration};
+
+use jsonrpsee::core::ClientError;
+
+use crate::primitives::Network;
+
+/// JSON-RPC error code for calls to methods the node does not know.
+const METHOD_NOT_FOUND_CODE: i32 = -32601;
+
+#[derive(Debug)]
+pub enum NimiqError {
+    /// The node does not support the called method, e.g. because it runs a different version or build.
+    /// Holds the name of the method.
+    MethodNotFound(String),
+    /// The URL given to the client does not use the `http` or `https` scheme.
+    UnsupportedScheme(String),
+    /// The credentials cannot be sent in an HTTP header.
+    InvalidCredentials,
+    /// An argument was rejected before sending the request to the node.
+    InvalidArgument(String),
+    /// A hex-encoded argument has an odd length or contains a non-hex character, at byte `position`.
+    InvalidHex { position: usize },
+    /// The node returned a different block or transaction than the one requested.
+    HashMismatch { expected: String, actual: String },
+    /// A transaction was meant for a different network than the one of the node.
+    NetworkMismatch { transaction: Network, node: Network },
+    /// A transaction cannot be included in the next block, because its validity start height is in the future
+    /// or more than `window` blocks in the past.
+    ValidityStartOutOfWindow {
+        validity_start_height: u32,
+        block_number: u32,
+        window: u32,
+    },
+    /// The node did not reach the awaited state within the given time.
+    Timeout(Duration),
+    /// The node answered with data the client cannot work with.
+    InvalidResponse(String),
+    /// Any other error of the underlying JSON-RPC client, including errors returned by the node.
+    Rpc(ClientError),
+}
+
+impl fmt::Display for NimiqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NimiqError::MethodNotFound(method) => write!(f, "Method not found: {}", method),
+            NimiqError::UnsupportedScheme(scheme) if scheme == "ws" || scheme == "wss" => write!(
+                f,
+                "Unsupported URL scheme `{}`: WebSocket endpoints are not supported by this HTTP client, use an `http` or `https` URL",
+                scheme
+            ),
+            NimiqError::UnsupportedScheme(scheme) => write!(
+                f,
+                "Unsupported URL scheme `{}`: expected `http` or `https`",
+                scheme
+            ),
+            NimiqError::InvalidCredentials => write!(f, "Invalid credentials"),
+            NimiqError::InvalidArgument(message) => write!(f, "Invalid argument: {}", message),
+            NimiqError::InvalidHex { position } => {
+                write!(f, "Invalid hex: odd length or non-hex character at position {}", position)
+            }
+            NimiqError::HashMismatch { expected, actual } => write!(
+                f,
+                "Hash mismatch: requested {}, but the node returned {}",
+                expected, actual
+            ),
+            NimiqError::NetworkMismatch { transaction, node } => write!(
+                f,
+                "Network mismatch: the transaction is for the {} network, but the node is on the {} network",
+                transaction, node
+            ),
+            NimiqError::ValidityStartOutOfWindow {
+                validity_start_height,
+                block_number,
+                window,
+            } => write!(
+                f,
+                "Validity start height {} is outside the validity window of {} blocks at block number {}",
+                validity_start_height, window, block_number
+            ),
+            NimiqError::Timeout(timeout) => write!(f, "Timed out after {:?}", timeout),
+            NimiqError::InvalidResponse(message) => write!(f, "Invalid response: {}", message),
+            NimiqError::Rpc(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for NimiqError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NimiqError::Rpc(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl NimiqError {
+    /// Converts the error of a call to `method`, recognizing methods the node does not know.
+    pub(crate) fn from_call(method: &str, error: ClientError) -> Self {
+        match error {
+            ClientError::Call(call) if call.code() == METHOD_NOT_FOUND_CODE => {
+                NimiqError::MethodNotFound(method.to_string())
+            }
+            error => NimiqError::Rpc(error),
+        }
+    }
+}
+
+impl From<ClientError> for NimiqError {
+    fn from(error: ClientError) -> Self {
+        NimiqError::Rpc(error)
+    }
+}
+
+impl From<serde_json::Error> for NimiqError {
+    fn from(error: serde_json::Error) -> Self {
+        NimiqError::Rpc(ClientError::ParseError(error))
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::error::NimiqError as Error;
+use crate::primitives::*;
+use crate::Client;
+
+/// A client for several nodes that keeps working as long as one of them does.
+///
+/// Reads and writes are handled differently:
+///
+/// * Reads are sent to one node at a time, starting with the node that answered the last read, and
+///   moving on to the next node whenever a node fails. The first successful answer is returned, so
+///   reads fail only if all nodes fail, in which case the error of the last node is returned.
+///   Note that a node that does not know a transaction yet also counts as failing.
+/// * Writes such as `send_raw_transaction` are broadcast to all nodes at the same time (or to the first
+///   `broadcast_limit` nodes, counting from the node that answered the last read) to maximize
+///   propagation. They succeed if at least one node accepts them, and return an error only if all
+///   nodes rejected them.
+#[derive(Debug)]
+pub struct FailoverClient {
+    clients: Vec<Client>,
+    /// Index of the node that answered the last read.
+    preferred: AtomicUsize,
+    broadcast_limit: usize,
+}
+
+impl FailoverClient {
+    /// Creates a client for the given nodes, which are tried in order.
+    ///
+    /// Panics if `clients` is empty.
+    pub fn new(clients: Vec<Client>) -> FailoverClient {
+        assert!(!clients.is_empty(), "At least one client is required");
+        FailoverClient {
+            broadcast_limit: clients.len(),
+            clients,
+            preferred: AtomicUsize::new(0),
+        }
+    }
+
+    /// Broadcasts writes to at most `limit` nodes instead of all of them.
+    pub fn with_broadcast_limit(mut self, limit: usize) -> FailoverClient {
+        self.broadcast_limit = limit.max(1);
+        self
+    }
+
+    /// Returns the clients, starting with the preferred one.
+    fn ordered(&self) -> impl Iterator<Item = (usize, &Client)> {
+        let preferred = self.preferred.load(Ordering::Relaxed);
+        (0..self.clients.len())
+            .map(move |i| (preferred + i) % self.clients.len())
+            .map(|i| (i, &self.clients[i]))
+    }
+
+    /// Runs a read on one node after the other until it succeeds, and remembers the node that answered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nimiq_rpc::{Client, FailoverClient};
+    /// use url::Url;
+    /// # tokio_test::block_on(async {
+    /// let client = FailoverClient::new(vec![
+    ///     Client::new(Url::parse("http://seed-host.com:8648").unwrap()),
+    ///     Client::new(Url::parse("http://other-host.com:8648").unwrap()),
+    /// ]);
+    /// let result = client.read(|client| async move { client.consensus().await }).await;
+    /// # })
+    /// ```
+    pub async fn read<T, F, Fut>(&self, read: F) -> Result<T, Error>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut last_error = None;
+        for (i, client) in self.ordered() {
+            match read(client.clone()).await {
+                Ok(result) => {
+                    self.preferred.store(i, Ordering::Relaxed);
+                    return Ok(result);
+                }
+                Err(error) => last_error = Some(error),
+            }
+        }
+        Err(last_error.unwrap())
+    }
+
+    /// Runs a write on up to `broadcast_limit` nodes concurrently and returns the first successful
+    /// result, or the last error if no node succeeded. Waits for all nodes to answer. The writes run
+    /// within the returned future and need no particular runtime, so dropping it cancels those still pending.
+    pub async fn broadcast<T, F, Fut>(&self, write: F) -> Result<T, Error>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let writes = self
+            .ordered()
+            .take(self.broadcast_limit)
+            .map(|(_, client)| write(client.clone()));
+        let mut first = None;
+        let mut last_error = None;
+        for result in futures_util::future::join_all(writes).await {
+            match result {
+                Ok(result) => first = first.or(Some(result)),
+                Err(error) => last_error = Some(error),
+            }
+        }
+        first.ok_or_else(|| last_error.unwrap())
+    }
+
+    /// Returns the height of the most recent block, see [`Client::block_number`].
+    pub async fn block_number(&self) -> Result<u32, Error> {
+        self.read(|client| async move { client.block_number().await })
+            .await
+    }
+
+    /// Returns details for the account of given address, see [`Client::get_account`].
+    pub async fn get_account(&self, id: &str) -> Result<Account, Error> {
+        self.read(|client| async move { client.get_account(id).await })
+            .await
+    }
+
+    /// Returns information about a transaction, see [`Client::get_transaction_by_hash`].
+    pub async fn get_transaction_by_hash(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionDetails, Error> {
+        self.read(|client| async move { client.get_transaction_by_hash(transaction_hash).await })
+            .await
+    }
+
+    /// Returns the receipt of a transaction, see [`Client::get_transaction_receipt`].
+    pub async fn get_transaction_receipt(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<TransactionReceipt, Error> {
+        self.read(|client| async move { client.get_transaction_receipt(transaction_hash).await })
+            .await
+    }
+
+    /// Broadcasts a signed transaction, see [`Client::send_raw_transaction`].
+    pub async fn send_raw_transaction(&self, transaction: &str) -> Result<String, Error> {
+        self.broadcast(|client| async move { client.send_raw_transaction(transaction).await })
+            .await
+    }
+
+    /// Signs a transaction on one node and broadcasts the signed transaction to all nodes, see [`Client::send_transaction`].
+    /// `Note` The transaction is signed once, with `create_raw_transaction` on the first node that succeeds, so the sender account must be unlocked on at least one node. Every node receives the same signed transaction, which is included at most once, even if `validity_start_height` is not set.
+    pub async fn send_transaction(
+        &self,
+        transaction: &OutgoingTransaction,
+    ) -> Result<String, Error> {
+        let raw_transaction = self
+            .read(|client| async move { client.create_raw_transaction(transaction).await })
+            .await?;
+        self.send_raw_transaction(&raw_transaction).await
+    }
+}
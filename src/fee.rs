@@ -0,0 +1,108 @@
+//! Fee recommendations based on the transactions waiting in the node's mempool.
+
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::error::NimiqError as Error;
+use crate::primitives::MempoolInfo;
+use crate::Client;
+
+/// Default time the mempool situation is reused before it is fetched again.
+const DEFAULT_TTL: Duration = Duration::from_secs(10);
+
+/// How quickly a transaction should be included in a block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeePriority {
+    /// Pays at least as much per byte as the cheapest pending transactions.
+    Low,
+    /// Pays at least as much per byte as half of the pending transactions.
+    Medium,
+    /// Pays at least as much per byte as 90% of the pending transactions.
+    High,
+}
+
+impl FeePriority {
+    /// Share of the pending transactions, ordered by fee per byte, to pay at least as much as.
+    fn quantile(self) -> f64 {
+        match self {
+            FeePriority::Low => 0.0,
+            FeePriority::Medium => 0.5,
+            FeePriority::High => 0.9,
+        }
+    }
+}
+
+struct Snapshot {
+    mempool: MempoolInfo,
+    min_fee_per_byte: u32,
+    fetched: Instant,
+}
+
+/// Suggests fees from the node's mempool, which is fetched at most once per TTL.
+pub struct FeeEstimator {
+    client: Client,
+    ttl: Duration,
+    snapshot: Mutex<Option<Snapshot>>,
+}
+
+impl FeeEstimator {
+    pub fn new(client: Client) -> FeeEstimator {
+        FeeEstimator {
+            client,
+            ttl: DEFAULT_TTL,
+            snapshot: Mutex::new(None),
+        }
+    }
+
+    /// Sets how long the mempool situation is reused before it is fetched again.
+    pub fn ttl(mut self, ttl: Duration) -> FeeEstimator {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns the fee in Lunas suggested for a transaction of `bytes` bytes.
+    ///
+    /// The fee per byte is the lower bound of the mempool bucket reached at the priority's share of
+    /// the pending transactions, but never less than the node's `min_fee_per_byte`, which is also used
+    /// when the mempool is empty.
+    pub async fn suggest_fee(&self, bytes: usize, priority: FeePriority) -> Result<u64, Error> {
+        let mut snapshot = self.snapshot.lock().await;
+        if snapshot
+            .as_ref()
+            .map_or(true, |snapshot| snapshot.fetched.elapsed() >= self.ttl)
+        {
+            *snapshot = Some(Snapshot {
+                mempool: self.client.mempool().await?,
+                min_fee_per_byte: self.client.min_fee_per_byte().await?,
+                fetched: Instant::now(),
+            });
+        }
+        let snapshot = snapshot.as_ref().unwrap();
+        let fee_per_byte = fee_per_byte(&snapshot.mempool, priority.quantile())
+            .map_or(snapshot.min_fee_per_byte, |fee| {
+                fee.max(snapshot.min_fee_per_byte)
+            });
+        Ok(u64::from(fee_per_byte) * bytes as u64)
+    }
+}
+
+/// Returns the lower bound of the bucket holding the transaction at `quantile` of the pending
+/// transactions ordered by fee per byte, or `None` if the mempool is empty.
+fn fee_per_byte(mempool: &MempoolInfo, quantile: f64) -> Option<u32> {
+    let mut buckets = mempool.buckets.clone();
+    buckets.sort_unstable();
+    let total: u32 = buckets.iter().map(|&bucket| mempool.count(bucket)).sum();
+    if total == 0 {
+        return None;
+    }
+    let position = (f64::from(total) * quantile) as u32;
+    let mut below = 0;
+    for bucket in buckets {
+        below += mempool.count(bucket);
+        if below > position {
+            return Some(bucket);
+        }
+    }
+    None
+}
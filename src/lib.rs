@@ -1,7 +1,23 @@
 pub extern crate jsonrpsee;
 
 mod client;
+mod error;
+mod failover;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod difficulty;
+pub mod fee;
+pub mod merkle;
+pub mod metrics;
+pub mod mining;
+#[cfg(feature = "pool")]
+pub mod pool;
+#[cfg(feature = "pos")]
+pub mod pos;
+pub mod prelude;
 pub mod primitives;
 
-pub use self::client::Client;
+pub use self::client::{Client, ClientBuilder, ClientConfig};
+pub use self::error::NimiqError;
+pub use self::failover::FailoverClient;
@@ -0,0 +1,104 @@
+//! Merkle trees as used for the body hash of Nimiq blocks.
+//!
+//! The body hash of a block is the root of a Merkle tree over the miner address, the extra data, the
+//! transactions and the pruned accounts of the block, in this order. Leaves are 32-byte hashes, e.g.
+//! transaction hashes, and inner nodes hash the concatenation of their children with Blake2b. A list of
+//! leaves is split with the larger half on the left.
+
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+/// A node of a Merkle path: the hash of the sibling subtree and on which side it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleNode {
+    /// Hex-encoded hash of the sibling subtree.
+    pub hash: String,
+    /// `true` if the sibling is the left child, i.e. is hashed before the path so far.
+    pub left: bool,
+}
+
+/// Returns the hex-encoded root of the tree over the hex-encoded leaf hashes, or `None` if a leaf is
+/// not a 32-byte hash.
+pub fn merkle_root(leaves: &[String]) -> Option<String> {
+    let leaves = decode_all(leaves)?;
+    Some(hex::encode(root(&leaves)))
+}
+
+/// Returns the path from the leaf at `index` to the root, ordered from the leaf upwards, or `None` if a
+/// leaf is not a 32-byte hash or `index` is out of bounds.
+pub fn merkle_path(leaves: &[String], index: usize) -> Option<Vec<MerkleNode>> {
+    let leaves = decode_all(leaves)?;
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut path = Vec::new();
+    let mut leaves = &leaves[..];
+    let mut index = index;
+    // Descend from the root to the leaf, then reverse to get the path upwards.
+    while leaves.len() > 1 {
+        let (left, right) = leaves.split_at(leaves.len().div_ceil(2));
+        if index < left.len() {
+            path.push(MerkleNode {
+                hash: hex::encode(root(right)),
+                left: false,
+            });
+            leaves = left;
+        } else {
+            path.push(MerkleNode {
+                hash: hex::encode(root(left)),
+                left: true,
+            });
+            index -= left.len();
+            leaves = right;
+        }
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Returns `true` if following `proof` from `tx_hash` leads to `merkle_root`, e.g. the `body_hash` of a
+/// block. Returns `false` if any of the hashes is malformed.
+pub fn verify_merkle_proof(tx_hash: &str, proof: &[MerkleNode], merkle_root: &str) -> bool {
+    let (Some(mut hash), Some(expected)) = (decode(tx_hash), decode(merkle_root)) else {
+        return false;
+    };
+    for node in proof {
+        let Some(sibling) = decode(&node.hash) else {
+            return false;
+        };
+        hash = if node.left {
+            concat_hash(&sibling, &hash)
+        } else {
+            concat_hash(&hash, &sibling)
+        };
+    }
+    hash == expected
+}
+
+fn root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    match leaves {
+        [] => Blake2b::<U32>::digest([]).into(),
+        [leaf] => *leaf,
+        leaves => {
+            let (left, right) = leaves.split_at(leaves.len().div_ceil(2));
+            concat_hash(&root(left), &root(right))
+        }
+    }
+}
+
+fn concat_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Blake2b::<U32>::new()
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+fn decode(hash: &str) -> Option<[u8; 32]> {
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hash.strip_prefix("0x").unwrap_or(hash), &mut bytes).ok()?;
+    Some(bytes)
+}
+
+fn decode_all(hashes: &[String]) -> Option<Vec<[u8; 32]>> {
+    hashes.iter().map(|hash| decode(hash)).collect()
+}
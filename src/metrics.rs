@@ -0,0 +1,135 @@
+//! In-process call statistics of a [`Client`](crate::Client), enabled with `Client::with_metrics`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+/// Upper bounds in microseconds of the latency histogram buckets. Slower calls fall into one more
+/// bucket.
+const BUCKET_BOUNDS: [u64; 14] = [
+    1_000, 2_000, 5_000, 10_000, 20_000, 50_000, 100_000, 200_000, 500_000, 1_000_000, 2_000_000,
+    5_000_000, 10_000_000, 30_000_000,
+];
+
+/// Call counts and latencies per method, shared by all clones of a client.
+///
+/// Counters are atomics, so recording a call only locks to look up the method.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    methods: Mutex<HashMap<String, Arc<MethodMetrics>>>,
+}
+
+#[derive(Debug, Default)]
+struct MethodMetrics {
+    calls: AtomicU64,
+    errors: AtomicU64,
+    total_micros: AtomicU64,
+    max_micros: AtomicU64,
+    buckets: [AtomicU64; BUCKET_BOUNDS.len() + 1],
+}
+
+impl Metrics {
+    pub(crate) fn record(&self, method: &str, elapsed: Duration, failed: bool) {
+        let metrics = {
+            let mut methods = self.methods.lock().unwrap();
+            match methods.get(method) {
+                Some(metrics) => metrics.clone(),
+                None => methods.entry(method.to_string()).or_default().clone(),
+            }
+        };
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        metrics.calls.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            metrics.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        metrics.total_micros.fetch_add(micros, Ordering::Relaxed);
+        metrics.max_micros.fetch_max(micros, Ordering::Relaxed);
+        let bucket = BUCKET_BOUNDS.partition_point(|bound| *bound < micros);
+        metrics.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the statistics of every method called so far, by method name.
+    ///
+    /// Counters are read one by one while calls may still be recorded, so the numbers of a method can be
+    /// off by the calls that complete during the snapshot.
+    pub fn snapshot(&self) -> BTreeMap<String, MethodStats> {
+        let methods = self.methods.lock().unwrap();
+        methods
+            .iter()
+            .map(|(method, metrics)| {
+                let stats = MethodStats {
+                    calls: metrics.calls.load(Ordering::Relaxed),
+                    errors: metrics.errors.load(Ordering::Relaxed),
+                    total_latency: Duration::from_micros(
+                        metrics.total_micros.load(Ordering::Relaxed),
+                    ),
+                    max_latency: Duration::from_micros(metrics.max_micros.load(Ordering::Relaxed)),
+                    buckets: metrics
+                        .buckets
+                        .iter()
+                        .map(|bucket| bucket.load(Ordering::Relaxed))
+                        .collect(),
+                };
+                (method.clone(), stats)
+            })
+            .collect()
+    }
+
+    /// Forgets all recorded calls.
+    pub fn reset(&self) {
+        self.methods.lock().unwrap().clear();
+    }
+}
+
+/// Statistics of the calls of one method. Batch requests count as method `batch`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodStats {
+    pub calls: u64,
+    /// Calls that failed, including errors returned by the node.
+    pub errors: u64,
+    pub total_latency: Duration,
+    pub max_latency: Duration,
+    buckets: Vec<u64>,
+}
+
+impl MethodStats {
+    /// Returns the mean latency, or `None` if there were no calls.
+    pub fn mean_latency(&self) -> Option<Duration> {
+        if self.calls == 0 {
+            return None;
+        }
+        let nanos = self.total_latency.as_nanos() / u128::from(self.calls);
+        Some(Duration::from_nanos(
+            u64::try_from(nanos).unwrap_or(u64::MAX),
+        ))
+    }
+
+    /// Returns an estimate of the latency below which the share `quantile` (between `0.0` and `1.0`) of the
+    /// calls completed, or `None` if there were no calls.
+    ///
+    /// Latencies are counted in buckets from 1 ms to 30 s, so the estimate is the upper bound of the bucket
+    /// the quantile falls into, at most the maximum latency.
+    pub fn percentile(&self, quantile: f64) -> Option<Duration> {
+        let total: u64 = self.buckets.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((total as f64 * quantile).ceil() as u64).clamp(1, total);
+        let mut seen = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                let bound = BUCKET_BOUNDS
+                    .get(bucket)
+                    .map_or(self.max_latency, |bound| Duration::from_micros(*bound));
+                return Some(bound.min(self.max_latency));
+            }
+        }
+        Some(self.max_latency)
+    }
+}
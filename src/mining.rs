@@ -0,0 +1,57 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+/// Salt used by the Nimiq proof-of-work function.
+const POW_SALT: &[u8] = b"nimiqrocks!";
+
+/// Memory cost (in KiB) of the Nimiq proof-of-work function.
+const POW_MEMORY_COST: u32 = 512;
+
+/// Length of a serialized block header in bytes.
+pub const HEADER_SIZE: usize = 146;
+
+/// Computes the Argon2d proof-of-work hash of a serialized block header.
+pub fn pow_hash(header: &[u8]) -> [u8; 32] {
+    let params = Params::new(POW_MEMORY_COST, 1, 1, Some(32)).unwrap();
+    let argon2 = Argon2::new(Algorithm::Argon2d, Version::V0x13, params);
+    let mut hash = [0u8; 32];
+    argon2
+        .hash_password_into(header, POW_SALT, &mut hash)
+        .unwrap();
+    hash
+}
+
+/// Computes the Blake2b block hash of a serialized block header.
+pub fn block_hash(header: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(header).into()
+}
+
+/// Expands a compact target (`nBits`) into its 256-bit big-endian representation.
+/// Same as [`difficulty::nbits_to_target`](crate::difficulty::nbits_to_target).
+pub fn compact_to_target(compact: u32) -> [u8; 32] {
+    crate::difficulty::nbits_to_target(compact)
+}
+
+/// Returns `true` if the given proof-of-work hash, read as a big-endian integer, is at most `target`.
+pub fn meets_target(hash: &[u8; 32], target: &[u8; 32]) -> bool {
+    hash <= target
+}
+
+/// Tries the nonces in `nonces` on a serialized block header and returns the first one whose proof-of-work
+/// hash meets `target`. The header is left with the last nonce tried.
+pub fn find_nonce(
+    header: &mut [u8],
+    target: &[u8; 32],
+    nonces: std::ops::RangeInclusive<u32>,
+) -> Option<u32> {
+    nonces.into_iter().find(|nonce| {
+        set_nonce(header, *nonce);
+        meets_target(&pow_hash(header), target)
+    })
+}
+
+/// Writes `nonce` into the last four bytes of a serialized block header.
+pub fn set_nonce(header: &mut [u8], nonce: u32) {
+    let len = header.len();
+    header[len - 4..].copy_from_slice(&nonce.to_be_bytes());
+}
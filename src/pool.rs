@@ -0,0 +1,133 @@
+//! An experimental CPU worker for mining in a pool through a node.
+//!
+//! When the node is connected to a pool, `getWork` returns the pool's share target and `submitBlock`
+//! forwards shares to the pool, so the worker only talks to the node. Hashing runs on a blocking thread
+//! of the tokio runtime, one round of nonces at a time. Like `Client::mine_once`, this is single-threaded
+//! and meant for test setups and experiments rather than competitive mining.
+
+use std::time::Duration;
+
+use crate::client::work_header;
+use crate::error::NimiqError as Error;
+use crate::mining;
+use crate::Client;
+
+/// Default number of nonces tried on one piece of work before fetching new work.
+const DEFAULT_NONCES_PER_ROUND: u32 = 10_000;
+
+/// How long `PoolWorker::run` waits after the first rejected share. The wait doubles with every further
+/// rejection in a row, up to `MAX_REJECTION_BACKOFF`.
+const REJECTION_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_REJECTION_BACKOFF: Duration = Duration::from_secs(10);
+
+/// A share accepted by the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Share {
+    /// Hex-encoded hash of the block header of the share.
+    pub block_hash: String,
+    pub nonce: u32,
+    /// `true` if the share also meets the network target and therefore is a block.
+    pub is_block: bool,
+}
+
+/// Repeatedly fetches work from the node, mines it to the target returned with the work and submits
+/// the shares found.
+pub struct PoolWorker {
+    client: Client,
+    nonces_per_round: u32,
+    on_share: Box<dyn FnMut(&Share) + Send>,
+    next_nonce: u32,
+}
+
+impl PoolWorker {
+    pub fn new(client: Client) -> PoolWorker {
+        PoolWorker {
+            client,
+            nonces_per_round: DEFAULT_NONCES_PER_ROUND,
+            on_share: Box::new(|_| {}),
+            next_nonce: 0,
+        }
+    }
+
+    /// Sets how many nonces are tried on one piece of work before fetching new work.
+    pub fn nonces_per_round(mut self, nonces: u32) -> PoolWorker {
+        self.nonces_per_round = nonces.max(1);
+        self
+    }
+
+    /// Sets a callback called for every share the node accepts.
+    pub fn on_share<F: FnMut(&Share) + Send + 'static>(mut self, on_share: F) -> PoolWorker {
+        self.on_share = Box::new(on_share);
+        self
+    }
+
+    /// Mines one round: fetches work, tries the next `nonces_per_round` nonces and submits the first
+    /// share found.
+    ///
+    /// Returns the share if the node accepted it, `None` if no nonce met the target. A share rejected
+    /// by the node, e.g. because it is stale, is returned as error like any other error.
+    pub async fn mine_round(&mut self) -> Result<Option<Share>, Error> {
+        self.round().await?.transpose()
+    }
+
+    /// Mines one round like `mine_round`, but keeps the outcome of submitting the share apart from
+    /// the other errors.
+    async fn round(&mut self) -> Result<Option<Result<Share, Error>>, Error> {
+        let work = self.client.get_work().await?;
+        let mut header = work_header(&work)?;
+        let target = work.pool_target();
+        let first = self.next_nonce;
+        let last = first.saturating_add(self.nonces_per_round - 1);
+        self.next_nonce = last.wrapping_add(1);
+
+        let search = tokio::task::spawn_blocking(move || {
+            let nonce = mining::find_nonce(&mut header, &target, first..=last);
+            (header, nonce)
+        });
+        let (header, nonce) = match search.await {
+            Ok(result) => result,
+            Err(error) => std::panic::resume_unwind(error.into_panic()),
+        };
+        let Some(nonce) = nonce else {
+            return Ok(None);
+        };
+
+        let mut block = hex::encode(&header);
+        block.push_str(&work.suffix);
+        if let Err(error) = self.client.submit_block(&block).await {
+            return Ok(Some(Err(error)));
+        }
+        let share = Share {
+            block_hash: hex::encode(mining::block_hash(&header)),
+            nonce,
+            is_block: work.is_block(&mining::pow_hash(&header)),
+        };
+        (self.on_share)(&share);
+        Ok(Some(Ok(share)))
+    }
+
+    /// Mines until `shares` shares were accepted, or forever if `None`.
+    ///
+    /// Shares the node rejects with an error response are skipped, waiting a little longer after every
+    /// rejection in a row. Stops at the first other error, including errors fetching work, e.g. when the
+    /// node cannot be reached or is not mining.
+    pub async fn run(&mut self, shares: Option<u64>) -> Result<(), Error> {
+        let mut accepted = 0;
+        let mut backoff = REJECTION_BACKOFF;
+        while shares.map_or(true, |shares| accepted < shares) {
+            match self.round().await? {
+                Some(Ok(_)) => {
+                    accepted += 1;
+                    backoff = REJECTION_BACKOFF;
+                }
+                None => {}
+                Some(Err(Error::Rpc(jsonrpsee::core::ClientError::Call(_)))) => {
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_REJECTION_BACKOFF);
+                }
+                Some(Err(error)) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+}
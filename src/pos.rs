@@ -0,0 +1,121 @@
+//! Types specific to the Albatross proof-of-stake chain.
+
+use serde::Deserialize;
+
+use crate::primitives::{de_u64_flexible, Coin};
+
+/// Address of the staking contract on Albatross.
+pub const STAKING_CONTRACT_ADDRESS: &str = "NQ77 0000 0000 0000 0000 0000 0000 0000 0001";
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingContract {
+    pub address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
+    pub balance: u64,
+    pub r#type: StakingContractType,
+    /// Number of validators in the active set. Only reported by some node versions.
+    #[serde(default)]
+    pub active_validators: Option<u32>,
+}
+
+impl StakingContract {
+    /// The total amount staked, which is the balance held by the staking contract.
+    pub fn total_stake(&self) -> u64 {
+        self.balance
+    }
+
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.balance)
+    }
+}
+
+/// Only matches the `"staking"` account type, so other Albatross accounts are not mistaken for the staking contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StakingContractType {
+    Staking,
+}
+
+/// Batch and epoch layout of an Albatross chain.
+///
+/// A batch ends with a macro block and an epoch ends with an election macro block. The default
+/// values match mainnet, test networks may use different ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Policy {
+    /// Height of the genesis block, which is the first election block.
+    pub genesis_block_number: u32,
+    pub blocks_per_batch: u32,
+    pub batches_per_epoch: u32,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Policy {
+            genesis_block_number: 3_456_000,
+            blocks_per_batch: 60,
+            batches_per_epoch: 720,
+        }
+    }
+}
+
+impl Policy {
+    pub fn blocks_per_epoch(&self) -> u32 {
+        self.blocks_per_batch * self.batches_per_epoch
+    }
+
+    /// Returns the epoch a block belongs to. The genesis block is in epoch 0.
+    pub fn epoch_at(&self, block_number: u32) -> u32 {
+        Self::period_at(
+            block_number,
+            self.genesis_block_number,
+            self.blocks_per_epoch(),
+        )
+    }
+
+    /// Returns the batch a block belongs to. The genesis block is in batch 0.
+    pub fn batch_at(&self, block_number: u32) -> u32 {
+        Self::period_at(
+            block_number,
+            self.genesis_block_number,
+            self.blocks_per_batch,
+        )
+    }
+
+    /// Returns `true` if the block at this height is a macro block.
+    pub fn is_macro_block(&self, block_number: u32) -> bool {
+        block_number >= self.genesis_block_number
+            && (block_number - self.genesis_block_number) % self.blocks_per_batch == 0
+    }
+
+    /// Returns `true` if the block at this height is an election macro block.
+    pub fn is_election_block(&self, block_number: u32) -> bool {
+        block_number >= self.genesis_block_number
+            && (block_number - self.genesis_block_number) % self.blocks_per_epoch() == 0
+    }
+
+    fn period_at(block_number: u32, genesis_block_number: u32, length: u32) -> u32 {
+        // Periods end with their macro block, so e.g. the first block after genesis starts period 1.
+        block_number
+            .saturating_sub(genesis_block_number)
+            .div_ceil(length)
+    }
+}
+
+/// Rewards and slashes applied by a block, which do not appear among its transactions.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inherent {
+    #[serde(rename = "type")]
+    pub ty: u8,
+    pub block_number: u32,
+    #[serde(default)]
+    pub block_time: Option<u64>,
+    pub target: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
+    pub value: u64,
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub hash: Option<String>,
+}
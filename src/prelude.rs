@@ -0,0 +1,18 @@
+//! Re-exports the types needed in most programs using the client.
+//!
+//! ```
+//! use nimiq_rpc::prelude::*;
+//! # tokio_test::block_on(async {
+//! let client = Client::new(Url::parse("http://seed-host.com:8648").unwrap());
+//! let result = client.get_block(BlockId::Latest, false).await;
+//! # })
+//! ```
+
+pub use url::Url;
+
+pub use crate::primitives::{
+    Account, AccountInfo, AccountType, Address, Block, BlockId, BlockKind, Coin, Direction, Hash,
+    Network, OutgoingTransaction, Transaction, TransactionDetails, TransactionReceipt,
+    TransactionSequence, TxOrHash, Wallet,
+};
+pub use crate::{Client, ClientBuilder, NimiqError};
@@ -1,47 +1,430 @@
+use std::collections::HashMap;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct Address {
-    pub id: String,
-    pub address: String,
+/// Deserializes hex strings with or without a `0x` prefix, as sent by different node versions, into
+/// lowercase hex without prefix.
+mod hex_string {
+    use serde::{Deserialize, Deserializer};
+
+    fn normalize(hex: &str) -> String {
+        hex.strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex)
+            .to_ascii_lowercase()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        Ok(normalize(&String::deserialize(deserializer)?))
+    }
+
+    pub fn deserialize_option<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<String>, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?.map(|hex| normalize(&hex)))
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-#[serde(untagged)]
+/// Deserializes amounts sent as JSON integers, as strings (as some node builds do to avoid precision
+/// loss in JavaScript) or in scientific notation, e.g. `1200000`, `"1200000"` or `1.2e6`.
+pub(crate) mod de_u64_flexible {
+    use std::fmt;
+
+    use serde::de::{Deserializer, Error, Visitor};
+
+    struct FlexibleU64;
+
+    impl<'de> Visitor<'de> for FlexibleU64 {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a non-negative integer, as number or string")
+        }
+
+        fn visit_u64<E: Error>(self, value: u64) -> Result<u64, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: Error>(self, value: i64) -> Result<u64, E> {
+            u64::try_from(value).map_err(|_| E::custom(format!("negative amount {}", value)))
+        }
+
+        fn visit_f64<E: Error>(self, value: f64) -> Result<u64, E> {
+            // `u64::MAX as f64` rounds up to 2^64, which is out of range.
+            if value.fract() == 0.0 && value >= 0.0 && value < u64::MAX as f64 {
+                Ok(value as u64)
+            } else {
+                Err(E::custom(format!("invalid amount {}", value)))
+            }
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<u64, E> {
+            let value = value.trim();
+            match value.parse::<u64>() {
+                Ok(value) => Ok(value),
+                Err(_) => match value.parse::<f64>() {
+                    Ok(float) => self.visit_f64(float),
+                    Err(_) => Err(E::custom(format!("invalid amount `{}`", value))),
+                },
+            }
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        deserializer.deserialize_any(FlexibleU64)
+    }
+}
+
+/// An account of any type. The variant is chosen by the `type` field of the account, so an account
+/// is never mistaken for another type that happens to have a subset of its fields.
+#[derive(Clone, Debug)]
 pub enum Account {
+    #[cfg(feature = "pos")]
+    Staking(crate::pos::StakingContract),
     HTLC(HTLCAccount),
     Vesting(VestingAccount),
     Basic(BasicAccount),
 }
 
+impl<'de> Deserialize<'de> for Account {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        // The type is a number, except for the Albatross staking contract, so serde's internal tagging does not apply.
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let account = match value.get("type") {
+            Some(serde_json::Value::Number(number)) => match number.as_u64() {
+                Some(0) => serde_json::from_value(value).map(Account::Basic),
+                Some(1) => serde_json::from_value(value).map(Account::Vesting),
+                Some(2) => serde_json::from_value(value).map(Account::HTLC),
+                _ => return Err(D::Error::custom(format!("unknown account type {}", number))),
+            },
+            #[cfg(feature = "pos")]
+            Some(serde_json::Value::String(name)) if name == "staking" => {
+                serde_json::from_value(value).map(Account::Staking)
+            }
+            Some(other) => return Err(D::Error::custom(format!("unknown account type {}", other))),
+            None => return Err(D::Error::missing_field("type")),
+        };
+        account.map_err(D::Error::custom)
+    }
+}
+
+impl Account {
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            #[cfg(feature = "pos")]
+            Account::Staking(_) => AccountType::Staking,
+            Account::HTLC(account) => AccountType::from(account.r#type),
+            Account::Vesting(account) => AccountType::from(account.r#type),
+            Account::Basic(account) => AccountType::from(account.r#type),
+        }
+    }
+
+    /// Returns the fields common to all account types.
+    pub fn info(&self) -> AccountInfo {
+        let (address, balance) = match self {
+            #[cfg(feature = "pos")]
+            Account::Staking(account) => (&account.address, account.balance),
+            Account::HTLC(account) => (&account.address, account.balance),
+            Account::Vesting(account) => (&account.address, account.balance),
+            Account::Basic(account) => (&account.address, account.balance),
+        };
+        AccountInfo {
+            address: address.clone(),
+            balance,
+            account_type: self.account_type(),
+        }
+    }
+
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.info().balance)
+    }
+
+    /// Returns `true` for vesting, HTLC and staking contracts.
+    pub fn is_contract(&self) -> bool {
+        matches!(
+            self.account_type(),
+            AccountType::Vesting | AccountType::Htlc | AccountType::Staking
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountInfo {
+    pub address: String,
+    pub balance: u64,
+    pub account_type: AccountType,
+}
+
+impl AccountInfo {
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.balance)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountType {
+    Basic,
+    Vesting,
+    Htlc,
+    /// The Albatross staking contract, which the node reports with the `"staking"` type rather than a number.
+    Staking,
+    Unknown(u8),
+}
+
+impl From<u8> for AccountType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AccountType::Basic,
+            1 => AccountType::Vesting,
+            2 => AccountType::Htlc,
+            other => AccountType::Unknown(other),
+        }
+    }
+}
+
+/// A Nimiq network, identified by the id transactions are signed for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum Network {
+    Main,
+    Test,
+    Dev,
+    Bounty,
+    Dummy,
+    Unknown(u8),
+}
+
+impl From<u8> for Network {
+    fn from(value: u8) -> Self {
+        match value {
+            42 => Network::Main,
+            1 => Network::Test,
+            2 => Network::Dev,
+            3 => Network::Bounty,
+            4 => Network::Dummy,
+            other => Network::Unknown(other),
+        }
+    }
+}
+
+impl From<Network> for u8 {
+    fn from(network: Network) -> Self {
+        match network {
+            Network::Main => 42,
+            Network::Test => 1,
+            Network::Dev => 2,
+            Network::Bounty => 3,
+            Network::Dummy => 4,
+            Network::Unknown(id) => id,
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Network::Main => write!(f, "main"),
+            Network::Test => write!(f, "test"),
+            Network::Dev => write!(f, "dev"),
+            Network::Bounty => write!(f, "bounty"),
+            Network::Dummy => write!(f, "dummy"),
+            Network::Unknown(id) => write!(f, "unknown ({})", id),
+        }
+    }
+}
+
+/// An amount of NIM, stored in Luna (1 NIM = 100 000 Luna).
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+#[serde(transparent)]
+pub struct Coin(pub u64);
+
+impl Coin {
+    /// Number of Luna in one NIM.
+    pub const LUNAS_PER_COIN: u64 = 100_000;
+
+    /// Formats the amount in NIM with all five decimals, grouping thousands with commas, e.g. `1,234.56789 NIM`.
+    pub fn format_with_unit(&self) -> String {
+        format!(
+            "{}.{:05} NIM",
+            group_thousands(self.0 / Self::LUNAS_PER_COIN),
+            self.0 % Self::LUNAS_PER_COIN
+        )
+    }
+
+    /// Formats the raw amount in Luna, e.g. `123456789`.
+    pub fn format_luna(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Adds two amounts, returning `None` on overflow.
+    pub fn checked_add(self, other: Coin) -> Option<Coin> {
+        self.0.checked_add(other.0).map(Coin)
+    }
+
+    /// Subtracts `other`, returning `None` if it is larger than `self`.
+    pub fn checked_sub(self, other: Coin) -> Option<Coin> {
+        self.0.checked_sub(other.0).map(Coin)
+    }
+
+    /// Sums all amounts, returning `None` on overflow.
+    pub fn try_sum<I: IntoIterator<Item = Coin>>(iter: I) -> Option<Coin> {
+        iter.into_iter()
+            .try_fold(Coin(0), |sum, coin| sum.checked_add(coin))
+    }
+}
+
+impl From<u64> for Coin {
+    fn from(luna: u64) -> Self {
+        Coin(luna)
+    }
+}
+
+/// Inserts a comma between each group of three digits.
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Formats a number of bytes with a binary unit and one decimal, e.g. `512 B` or `1.2 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Compare the value as displayed, so that e.g. 1023.96 KiB shows as 1.0 MiB rather than 1024.0 KiB.
+    while (value * 10.0).round() / 10.0 >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// A 32-byte block or transaction hash, parsed from and displayed as lowercase hex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Hash(pub [u8; 32]);
+
+impl Hash {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for Hash {
+    type Err = HashError;
+
+    /// Parses 64 hex digits, in lower or upper case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 64 {
+            return Err(HashError::InvalidLength(s.len()));
+        }
+        let mut hash = [0u8; 32];
+        hex::decode_to_slice(s, &mut hash).map_err(|_| HashError::InvalidHex)?;
+        Ok(Hash(hash))
+    }
+}
+
+impl TryFrom<String> for Hash {
+    type Error = HashError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Hash> for String {
+    fn from(hash: Hash) -> Self {
+        hash.to_string()
+    }
+}
+
+impl fmt::Display for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+/// Why a string is not a valid [`Hash`](struct@Hash).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashError {
+    /// The string does not have 64 characters. Holds its length.
+    InvalidLength(usize),
+    /// The string contains characters other than hex digits.
+    InvalidHex,
+}
+
+impl fmt::Display for HashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashError::InvalidLength(length) => {
+                write!(f, "expected 64 hex digits, got {} characters", length)
+            }
+            HashError::InvalidHex => write!(f, "invalid hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for HashError {}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct BasicAccount {
     pub id: String,
     pub address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub balance: u64,
     pub r#type: u8,
 }
 
+impl BasicAccount {
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.balance)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VestingAccount {
     pub id: String,
     pub address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub balance: u64,
     pub r#type: u8,
     pub owner: String,
     pub owner_address: String,
     pub vesting_start: u32,
     pub vesting_step_blocks: u32,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub vesting_step_amount: u64,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub vesting_total_amount: u64,
 }
 
+impl VestingAccount {
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.balance)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HTLCAccount {
     pub id: String,
     pub address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub balance: u64,
     pub r#type: u8,
     pub sender: String,
@@ -52,9 +435,76 @@ pub struct HTLCAccount {
     pub hash_algorithm: u8,
     pub hash_count: u8,
     pub timeout: u32,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub total_amount: u64,
 }
 
+impl HTLCAccount {
+    pub fn balance_coin(&self) -> Coin {
+        Coin(self.balance)
+    }
+
+    pub fn hash_algorithm_enum(&self) -> HashAlgorithm {
+        HashAlgorithm::from(self.hash_algorithm)
+    }
+
+    /// Returns `true` if the recipient can redeem the contract with `preimage` in block `current_block`:
+    /// hashing `preimage` `hash_count` times with the contract's hash algorithm must give `hash_root`,
+    /// and the contract must not have timed out yet. Like the nodes, this accepts redeeming up to and
+    /// including the block at height `timeout`.
+    pub fn can_resolve_with(&self, preimage: &[u8], current_block: u32) -> bool {
+        if current_block > self.timeout {
+            return false;
+        }
+        let algorithm = self.hash_algorithm_enum();
+        let mut hash = preimage.to_vec();
+        for _ in 0..self.hash_count {
+            match algorithm.hash(&hash) {
+                Some(next) => hash = next,
+                None => return false,
+            }
+        }
+        hex::encode(hash).eq_ignore_ascii_case(&self.hash_root)
+    }
+}
+
+/// Hash algorithm used for the hash root of an HTLC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Blake2b,
+    Argon2d,
+    Sha256,
+    Sha512,
+    Unknown(u8),
+}
+
+impl HashAlgorithm {
+    /// Hashes `data`, or returns `None` for unknown algorithms.
+    pub fn hash(&self, data: &[u8]) -> Option<Vec<u8>> {
+        use blake2::{digest::consts::U32, Blake2b, Digest};
+
+        match self {
+            HashAlgorithm::Blake2b => Some(Blake2b::<U32>::digest(data).to_vec()),
+            HashAlgorithm::Argon2d => Some(crate::mining::pow_hash(data).to_vec()),
+            HashAlgorithm::Sha256 => Some(sha2::Sha256::digest(data).to_vec()),
+            HashAlgorithm::Sha512 => Some(sha2::Sha512::digest(data).to_vec()),
+            HashAlgorithm::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<u8> for HashAlgorithm {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => HashAlgorithm::Blake2b,
+            2 => HashAlgorithm::Argon2d,
+            3 => HashAlgorithm::Sha256,
+            4 => HashAlgorithm::Sha512,
+            other => HashAlgorithm::Unknown(other),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AccountsTreeChunk {
     pub nodes: Vec<AccountsTreeNode>,
@@ -68,49 +518,223 @@ pub struct AccountsTreeNode {
     pub account: Account,
 }
 
+/// The pending transactions in the node's mempool, counted per fee-per-byte bucket.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MempoolInfo {
+    pub total: u32,
+    /// Lower bounds in Luna per byte of the buckets holding transactions, highest first.
+    pub buckets: Vec<u32>,
+    #[serde(flatten)]
+    counts: HashMap<String, u32>,
+}
+
+impl MempoolInfo {
+    /// Returns the number of transactions in the bucket starting at `fee_per_byte`.
+    pub fn count(&self, fee_per_byte: u32) -> u32 {
+        self.counts
+            .get(&fee_per_byte.to_string())
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// A block as returned by the node.
+///
+/// Albatross (PoS) blocks have no proof of work, so `pow`, `nonce`, `miner`, `miner_address` and
+/// `difficulty` are empty or zero for them, `accounts_hash` holds their `stateHash`, and `history_hash`
+/// is only set for them.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Block {
+    /// Height of the block. `u32` like in the nodes, see `Client::block_number` for why this suffices.
     pub number: u32,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub hash: String,
+    /// Empty if the node omits it, as some versions do for the genesis block.
+    #[serde(default, deserialize_with = "hex_string::deserialize")]
     pub pow: String,
+    /// All zeros for the genesis block, or empty if the node omits it.
+    #[serde(default, deserialize_with = "hex_string::deserialize")]
     pub parent_hash: String,
+    #[serde(default)]
     pub nonce: u32,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub body_hash: String,
+    #[serde(alias = "stateHash", deserialize_with = "hex_string::deserialize")]
     pub accounts_hash: String,
+    /// Root of the history tree of an Albatross block, empty on PoW chains.
+    #[serde(default, deserialize_with = "hex_string::deserialize")]
+    pub history_hash: String,
+    #[serde(default, deserialize_with = "hex_string::deserialize")]
     pub miner: String,
+    #[serde(default)]
     pub miner_address: String,
+    #[serde(default)]
     pub difficulty: String,
+    #[serde(default, deserialize_with = "hex_string::deserialize")]
     pub extra_data: String,
     pub size: u32,
-    pub timestamp: u32,
+    /// In seconds on PoW chains and in milliseconds on Albatross.
+    pub timestamp: u64,
+    /// Empty if the node omits it, as some versions do for blocks without transactions.
+    #[serde(default)]
     pub transactions: TransactionSequence,
+    /// `"micro"` or `"macro"` on Albatross, empty on PoW chains. See `Block::kind`.
+    #[serde(default, rename = "type")]
+    pub block_type: String,
+    /// Whether an Albatross macro block ends an epoch and elects the next validator set.
+    #[serde(default)]
+    pub is_election_block: bool,
+}
+
+impl Block {
+    /// Returns the target derived from `difficulty`, or `None` if the difficulty is missing or malformed,
+    /// as on Albatross.
+    pub fn target(&self) -> Option<[u8; 32]> {
+        let difficulty: f64 = self.difficulty.parse().ok()?;
+        Some(crate::difficulty::difficulty_to_target(difficulty))
+    }
+
+    /// Projects the block onto a [`Header`], so that header-only code can handle it like a [`FullBlock`].
+    /// `Note` The mapping is lossy: the block carries no version or interlink hash, so `version` is 1 and
+    /// `interlink_hash` is empty. `n_bits` is derived from `difficulty` and is 0 if there is none, as on Albatross.
+    pub fn to_header(&self) -> Header {
+        Header {
+            version: 1,
+            prev_hash: self.parent_hash.clone(),
+            interlink_hash: String::new(),
+            accounts_hash: self.accounts_hash.clone(),
+            n_bits: self
+                .target()
+                .map(|target| crate::difficulty::target_to_nbits(&target))
+                .unwrap_or(0),
+            height: self.number,
+        }
+    }
+
+    pub fn kind(&self) -> BlockKind {
+        match self.block_type.as_str() {
+            "micro" => BlockKind::Micro,
+            "macro" if self.is_election_block => BlockKind::MacroElection,
+            "macro" => BlockKind::MacroCheckpoint,
+            _ => BlockKind::ProofOfWork,
+        }
+    }
+}
+
+/// The kind of a block, as classified by `Block::kind`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockKind {
+    /// A block of a PoW chain.
+    ProofOfWork,
+    /// An Albatross micro block, produced by a single validator and carrying transactions.
+    Micro,
+    /// An Albatross macro block finalizing a batch.
+    MacroCheckpoint,
+    /// An Albatross macro block ending an epoch, where the validator set changes.
+    MacroElection,
+}
+
+/// A block without its transactions, for views listing many blocks.
+#[derive(Clone, Debug)]
+pub struct BlockSummary {
+    pub number: u32,
+    pub hash: String,
+    pub pow: String,
+    pub parent_hash: String,
+    pub nonce: u32,
+    pub body_hash: String,
+    pub accounts_hash: String,
+    /// Empty on PoW chains, see `Block::history_hash`.
+    pub history_hash: String,
+    pub miner: String,
+    pub miner_address: String,
+    pub difficulty: String,
+    pub extra_data: String,
+    pub size: u32,
+    pub timestamp: u64,
+    pub transaction_count: u16,
+}
+
+impl From<Block> for BlockSummary {
+    fn from(block: Block) -> Self {
+        BlockSummary {
+            transaction_count: u16::try_from(block.transactions.len()).unwrap_or(u16::MAX),
+            number: block.number,
+            hash: block.hash,
+            pow: block.pow,
+            parent_hash: block.parent_hash,
+            nonce: block.nonce,
+            body_hash: block.body_hash,
+            accounts_hash: block.accounts_hash,
+            history_hash: block.history_hash,
+            miner: block.miner,
+            miner_address: block.miner_address,
+            difficulty: block.difficulty,
+            extra_data: block.extra_data,
+            size: block.size,
+            timestamp: block.timestamp,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockId {
+    Hash(String),
+    Number(u32),
+    Latest,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FullBlock {
     pub header: Header,
     pub interlink: String,
+    /// Compact target (`nBits`) of the block.
     pub target: u64,
     pub body: Body,
 }
 
+impl FullBlock {
+    pub fn difficulty(&self) -> f64 {
+        crate::difficulty::target_to_difficulty(&crate::difficulty::nbits_to_target(
+            self.target as u32,
+        ))
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Header {
     pub version: u16,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub prev_hash: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub interlink_hash: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub accounts_hash: String,
     pub n_bits: u32,
     pub height: u32,
 }
 
+impl Header {
+    /// Returns the target encoded in `n_bits`.
+    pub fn target(&self) -> [u8; 32] {
+        crate::difficulty::nbits_to_target(self.n_bits)
+    }
+
+    pub fn difficulty(&self) -> f64 {
+        crate::difficulty::target_to_difficulty(&self.target())
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Body {
+    #[serde(deserialize_with = "hex_string::deserialize")]
     hash: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     miner_addr: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     extra_data: String,
     transactions: Vec<String>,
     merkle_hashes: Vec<String>,
@@ -122,9 +746,47 @@ pub struct GetWork {
     pub data: String,
     pub suffix: String,
     pub target: u64,
+    /// Empty if the node omits it, as versions from before the field was added do.
+    #[serde(default)]
     pub algorithm: String,
 }
 
+/// `target` is the compact target the work has to meet. When the node is connected to a pool this is the
+/// pool's share target, which is easier than the network target encoded in the block header of `data`. A hash
+/// meeting the share target is a valid pool share, only a hash that also meets the network target is a block.
+impl GetWork {
+    /// Returns the target hashes have to meet to be accepted as pool shares.
+    pub fn pool_target(&self) -> [u8; 32] {
+        crate::difficulty::nbits_to_target(self.target as u32)
+    }
+
+    /// Returns the difficulty of the pool share target.
+    pub fn pool_difficulty(&self) -> f64 {
+        crate::difficulty::target_to_difficulty(&self.pool_target())
+    }
+
+    /// Returns the target of the network, read from the block header, or `None` if `data` is not a valid header.
+    pub fn network_target(&self) -> Option<[u8; 32]> {
+        let header = hex::decode(&self.data).ok()?;
+        if header.len() != crate::mining::HEADER_SIZE {
+            return None;
+        }
+        let n_bits = u32::from_be_bytes(header[130..134].try_into().ok()?);
+        Some(crate::difficulty::nbits_to_target(n_bits))
+    }
+
+    /// Returns `true` if the proof-of-work hash meets the target of this work, i.e. is a valid share.
+    pub fn is_pool_share(&self, hash: &[u8; 32]) -> bool {
+        crate::mining::meets_target(hash, &self.pool_target())
+    }
+
+    /// Returns `true` if the proof-of-work hash meets the network target, i.e. solves the block.
+    pub fn is_block(&self, hash: &[u8; 32]) -> bool {
+        self.network_target()
+            .is_some_and(|target| crate::mining::meets_target(hash, &target))
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PeerList {
@@ -132,14 +794,50 @@ pub struct PeerList {
     pub address: String,
     pub address_state: u64,
     pub connection_state: Option<u64>,
+    /// Protocol version announced by the peer, not its software version.
     pub version: Option<u64>,
     pub time_offset: Option<i64>,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub head_hash: Option<String>,
     pub latency: Option<u64>,
     pub rx: Option<u64>,
     pub tx: Option<u64>,
 }
 
+impl PeerList {
+    pub fn address_state(&self) -> AddressState {
+        AddressState::from(self.address_state)
+    }
+
+    pub fn connection_state(&self) -> Option<ConnectionState> {
+        self.connection_state.map(ConnectionState::from)
+    }
+
+    /// Returns the bytes received from the peer, formatted with `format_bytes`.
+    pub fn rx_human(&self) -> Option<String> {
+        self.rx.map(format_bytes)
+    }
+
+    /// Returns the bytes sent to the peer, formatted with `format_bytes`.
+    pub fn tx_human(&self) -> Option<String> {
+        self.tx.map(format_bytes)
+    }
+}
+
+impl fmt::Display for PeerList {
+    /// Formats a one-line summary, e.g. `wss://seed1.nimiq.com:8443/... address: established, connection: established, latency: 42 ms`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} address: {}", self.address, self.address_state())?;
+        if let Some(state) = self.connection_state() {
+            write!(f, ", connection: {}", state)?;
+        }
+        if let Some(latency) = self.latency {
+            write!(f, ", latency: {} ms", latency)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PeerState {
@@ -148,6 +846,96 @@ pub struct PeerState {
     pub address_state: u8,
 }
 
+impl PeerState {
+    pub fn address_state(&self) -> AddressState {
+        AddressState::from(u64::from(self.address_state))
+    }
+}
+
+impl fmt::Display for PeerState {
+    /// Formats a one-line summary, e.g. `wss://seed1.nimiq.com:8443/... address: banned`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} address: {}", self.address, self.address_state())
+    }
+}
+
+/// State of a peer address as known to the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressState {
+    New,
+    Established,
+    Tried,
+    Failed,
+    Banned,
+    Unknown(u64),
+}
+
+impl From<u64> for AddressState {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => AddressState::New,
+            2 => AddressState::Established,
+            3 => AddressState::Tried,
+            4 => AddressState::Failed,
+            5 => AddressState::Banned,
+            other => AddressState::Unknown(other),
+        }
+    }
+}
+
+impl fmt::Display for AddressState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressState::New => write!(f, "new"),
+            AddressState::Established => write!(f, "established"),
+            AddressState::Tried => write!(f, "tried"),
+            AddressState::Failed => write!(f, "failed"),
+            AddressState::Banned => write!(f, "banned"),
+            AddressState::Unknown(value) => write!(f, "unknown ({})", value),
+        }
+    }
+}
+
+/// State of the node's connection to a peer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    New,
+    Connecting,
+    Connected,
+    Negotiating,
+    Established,
+    Closed,
+    Unknown(u64),
+}
+
+impl From<u64> for ConnectionState {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => ConnectionState::New,
+            2 => ConnectionState::Connecting,
+            3 => ConnectionState::Connected,
+            4 => ConnectionState::Negotiating,
+            5 => ConnectionState::Established,
+            6 => ConnectionState::Closed,
+            other => ConnectionState::Unknown(other),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionState::New => write!(f, "new"),
+            ConnectionState::Connecting => write!(f, "connecting"),
+            ConnectionState::Connected => write!(f, "connected"),
+            ConnectionState::Negotiating => write!(f, "negotiating"),
+            ConnectionState::Established => write!(f, "established"),
+            ConnectionState::Closed => write!(f, "closed"),
+            ConnectionState::Unknown(value) => write!(f, "unknown ({})", value),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(untagged)]
 pub enum Syncing {
@@ -166,70 +954,396 @@ pub struct Pending {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub hash: String,
-    pub blocktxn_hash: String,
+    /// Sent as `blockHash` by the node. Older versions of this crate expected `blocktxnHash`.
+    #[serde(alias = "blocktxnHash", deserialize_with = "hex_string::deserialize")]
+    pub block_hash: String,
     pub block_number: u32,
     pub timestamp: u32,
     pub confirmations: u32,
     pub transaction_index: Option<i32>,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub from: String,
     pub from_address: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub to: String,
     pub to_address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub value: u64,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub fee: u64,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub data: Option<String>,
+    /// Zero if the node omits it, as some versions do for transactions without flags.
+    #[serde(default)]
     pub flags: u8,
 }
 
+impl Transaction {
+    pub fn flags_typed(&self) -> TransactionFlags {
+        TransactionFlags::from(self.flags)
+    }
+
+    pub fn value_coin(&self) -> Coin {
+        Coin(self.value)
+    }
+
+    pub fn fee_coin(&self) -> Coin {
+        Coin(self.fee)
+    }
+}
+
+/// The flags of a transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransactionFlags(pub u8);
+
+impl TransactionFlags {
+    /// The transaction creates a contract, e.g. a vesting contract or an HTLC.
+    pub const CONTRACT_CREATION: TransactionFlags = TransactionFlags(0b01);
+    /// The transaction only signals something to the recipient, such as a staking update on Albatross.
+    pub const SIGNALING: TransactionFlags = TransactionFlags(0b10);
+
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if all flags set in `other` are also set in `self`.
+    pub fn contains(&self, other: TransactionFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_contract_creation(&self) -> bool {
+        self.contains(TransactionFlags::CONTRACT_CREATION)
+    }
+
+    pub fn is_signaling(&self) -> bool {
+        self.contains(TransactionFlags::SIGNALING)
+    }
+}
+
+impl From<u8> for TransactionFlags {
+    fn from(bits: u8) -> Self {
+        TransactionFlags(bits)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionDetails {
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub hash: String,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub block_hash: Option<String>,
     pub block_number: Option<u32>,
     pub timestamp: Option<u32>,
     pub confirmations: Option<u32>,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub from: String,
     pub from_address: String,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub to: String,
     pub to_address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub value: u64,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub fee: u64,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub data: Option<String>,
+    /// Hex-encoded signature proof of the sender: its public key, a Merkle path (empty for single-signature
+    /// wallets) and the signature. `None` if the node sends `null` or omits it.
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub proof: Option<String>,
+    /// Zero if the node omits it, as some versions do for transactions without flags.
+    #[serde(default)]
     pub flags: u8,
 }
 
+impl TransactionDetails {
+    pub fn flags_typed(&self) -> TransactionFlags {
+        TransactionFlags::from(self.flags)
+    }
+
+    /// Returns the decoded `proof`, or `None` if there is none or it is not valid hex.
+    pub fn signature_proof_bytes(&self) -> Option<Vec<u8>> {
+        hex::decode(self.proof.as_ref()?).ok()
+    }
+
+    pub fn value_coin(&self) -> Coin {
+        Coin(self.value)
+    }
+
+    pub fn fee_coin(&self) -> Coin {
+        Coin(self.fee)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Sent,
+    Received,
+    All,
+}
+
+impl Direction {
+    /// Returns `true` if `transaction` goes in this direction as seen from `address`.
+    /// The address can be given in user friendly or hex format.
+    pub fn matches(&self, transaction: &TransactionDetails, address: &str) -> bool {
+        let sent = same_address(address, &transaction.from_address)
+            || same_address(address, &transaction.from);
+        let received = same_address(address, &transaction.to_address)
+            || same_address(address, &transaction.to);
+        match self {
+            Direction::Sent => sent,
+            Direction::Received => received,
+            Direction::All => sent || received,
+        }
+    }
+}
+
+fn same_address(a: &str, b: &str) -> bool {
+    if let (Some(a), Some(b)) = (address_bytes(a), address_bytes(b)) {
+        return a == b;
+    }
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect::<String>()
+    };
+    normalize(a) == normalize(b)
+}
+
+const ADDRESS_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKLMNPQRSTUVXY";
+
+/// Parses an address in user friendly format (with or without spaces) or hex format into its 20 bytes.
+/// Returns `None` for malformed addresses or a wrong checksum.
+pub(crate) fn address_bytes(address: &str) -> Option<[u8; 20]> {
+    address.parse::<Address>().ok().map(|address| address.0)
+}
+
+/// Computes the IBAN remainder (mod 97) of a string of digits and uppercase letters.
+fn iban_remainder(s: &str) -> Option<u32> {
+    let mut remainder = 0u32;
+    for c in s.chars() {
+        let value = c.to_digit(36)?;
+        remainder = if value < 10 {
+            (remainder * 10 + value) % 97
+        } else {
+            (remainder * 100 + value) % 97
+        };
+    }
+    Some(remainder)
+}
+
+/// A 20-byte Nimiq address.
+///
+/// Parsed from the user friendly format (`NQ07 0000 ...`, with or without spaces, in any case) or from
+/// 40 hex digits, and displayed in the user friendly format. Addresses compare equal if their bytes
+/// are equal, whatever format they were parsed from.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Address(pub [u8; 20]);
+
+impl Address {
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Formats the address as 40 lowercase hex digits, the format of `from` and `to` in transactions.
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    /// Formats the address in the user friendly format, e.g. `NQ07 0000 0000 0000 0000 0000 0000 0000 0000`.
+    pub fn to_user_friendly(&self) -> String {
+        let mut base32 = String::with_capacity(32);
+        for chunk in self.0.chunks(5) {
+            let bits = chunk
+                .iter()
+                .fold(0u64, |bits, byte| (bits << 8) | u64::from(*byte));
+            for i in (0..8).rev() {
+                base32.push(ADDRESS_ALPHABET[((bits >> (i * 5)) & 31) as usize] as char);
+            }
+        }
+        let check = 98 - iban_remainder(&format!("{}NQ00", base32)).unwrap();
+        let compact = format!("NQ{:02}{}", check, base32);
+        compact
+            .as_bytes()
+            .chunks(4)
+            .map(|group| std::str::from_utf8(group).unwrap())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let compact: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if !compact.is_ascii() {
+            return Err(AddressError::InvalidCharacter);
+        }
+        if compact.len() == 40 {
+            let mut bytes = [0u8; 20];
+            hex::decode_to_slice(&compact, &mut bytes)
+                .map_err(|_| AddressError::InvalidCharacter)?;
+            return Ok(Address(bytes));
+        }
+        if compact.len() != 36 {
+            return Err(AddressError::InvalidLength(compact.len()));
+        }
+        if !compact.starts_with("NQ") {
+            return Err(AddressError::InvalidCountryCode);
+        }
+
+        // IBAN checksum: move the country code and check digits to the end, then take the number mod 97.
+        let rearranged = format!("{}{}", &compact[4..], &compact[..4]);
+        match iban_remainder(&rearranged) {
+            Some(1) => {}
+            Some(_) => return Err(AddressError::InvalidChecksum),
+            None => return Err(AddressError::InvalidCharacter),
+        }
+
+        let mut bytes = [0u8; 20];
+        let mut buffer = 0u32;
+        let mut bits = 0;
+        let mut index = 0;
+        for c in compact[4..].bytes() {
+            let value = ADDRESS_ALPHABET
+                .iter()
+                .position(|a| *a == c)
+                .ok_or(AddressError::InvalidCharacter)? as u32;
+            buffer = (buffer << 5) | value;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes[index] = (buffer >> bits) as u8;
+                index += 1;
+            }
+        }
+        Ok(Address(bytes))
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = AddressError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Address> for String {
+    fn from(address: Address) -> Self {
+        address.to_string()
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_user_friendly())
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Address({})", self)
+    }
+}
+
+/// Why a string is not a valid [`Address`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressError {
+    /// The string has neither 36 (user friendly format) nor 40 (hex) characters, not counting spaces.
+    /// Holds its length.
+    InvalidLength(usize),
+    /// A user friendly address that does not start with `NQ`.
+    InvalidCountryCode,
+    /// The string contains characters that are not allowed in its format.
+    InvalidCharacter,
+    /// The check digits of a user friendly address do not match, e.g. because of a typo.
+    InvalidChecksum,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::InvalidLength(length) => write!(
+                f,
+                "expected 36 characters or 40 hex digits, got {} characters",
+                length
+            ),
+            AddressError::InvalidCountryCode => write!(f, "address does not start with NQ"),
+            AddressError::InvalidCharacter => write!(f, "invalid character"),
+            AddressError::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionDetails2 {
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub hash: String,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub block_hash: Option<String>,
     pub block_number: Option<u32>,
     pub timestamp: Option<u32>,
     pub confirmations: Option<u32>,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub from: String,
     pub from_address: String,
     pub from_type: u8,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub to: String,
     pub to_type: u8,
     pub to_address: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub value: u64,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub fee: u64,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub data: Option<String>,
+    #[serde(default, deserialize_with = "hex_string::deserialize_option")]
     pub proof: Option<String>,
+    /// Zero if the node omits it, as some versions do for transactions without flags.
+    #[serde(default)]
     pub flags: u8,
     pub validity_start_height: u32,
     pub network_id: u8,
 }
 
+impl TransactionDetails2 {
+    pub fn flags_typed(&self) -> TransactionFlags {
+        TransactionFlags::from(self.flags)
+    }
+
+    pub fn value_coin(&self) -> Coin {
+        Coin(self.value)
+    }
+
+    pub fn fee_coin(&self) -> Coin {
+        Coin(self.fee)
+    }
+}
+
+/// Where a transaction was included. The node does not report the fee here, see `Client::transaction_fee`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionReceipt {
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub transaction_hash: String,
     pub transaction_index: i32,
     pub block_number: u32,
+    #[serde(deserialize_with = "hex_string::deserialize")]
     pub block_hash: String,
     pub confirmations: u32,
     pub timestamp: u32,
@@ -241,15 +1355,225 @@ pub struct TransactionReceipt {
 pub enum TransactionSequence {
     BlockHashes(Vec<String>),
     Transactions(Vec<Transaction>),
+    /// Hashes and full transactions mixed, as some nodes return for blocks fetched without full transactions.
+    Mixed(Vec<TransactionOrHash>),
+}
+
+impl Default for TransactionSequence {
+    fn default() -> Self {
+        TransactionSequence::BlockHashes(Vec::new())
+    }
+}
+
+impl TransactionSequence {
+    pub fn len(&self) -> usize {
+        match self {
+            TransactionSequence::BlockHashes(hashes) => hashes.len(),
+            TransactionSequence::Transactions(transactions) => transactions.len(),
+            TransactionSequence::Mixed(entries) => entries.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<TxOrHash<'_>> {
+        match self {
+            TransactionSequence::BlockHashes(hashes) => {
+                hashes.get(index).map(|hash| TxOrHash::Hash(hash))
+            }
+            TransactionSequence::Transactions(transactions) => {
+                transactions.get(index).map(TxOrHash::Full)
+            }
+            TransactionSequence::Mixed(entries) => entries.get(index).map(TxOrHash::from),
+        }
+    }
+
+    pub fn iter(&self) -> TransactionSequenceIter<'_> {
+        match self {
+            TransactionSequence::BlockHashes(hashes) => {
+                TransactionSequenceIter::BlockHashes(hashes.iter())
+            }
+            TransactionSequence::Transactions(transactions) => {
+                TransactionSequenceIter::Transactions(transactions.iter())
+            }
+            TransactionSequence::Mixed(entries) => TransactionSequenceIter::Mixed(entries.iter()),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a TransactionSequence {
+    type Item = TxOrHash<'a>;
+    type IntoIter = TransactionSequenceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A transaction of a block, either as its hash or as the full object depending on how the block was fetched.
+#[derive(Clone, Copy, Debug)]
+pub enum TxOrHash<'a> {
+    Hash(&'a str),
+    Full(&'a Transaction),
+}
+
+impl<'a> TxOrHash<'a> {
+    pub fn hash(&self) -> &'a str {
+        match self {
+            TxOrHash::Hash(hash) => hash,
+            TxOrHash::Full(transaction) => &transaction.hash,
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+impl<'a> From<&'a TransactionOrHash> for TxOrHash<'a> {
+    fn from(entry: &'a TransactionOrHash) -> Self {
+        match entry {
+            TransactionOrHash::Hash(hash) => TxOrHash::Hash(hash),
+            TransactionOrHash::Full(transaction) => TxOrHash::Full(transaction),
+        }
+    }
+}
+
+/// An owned element of a [`TransactionSequence::Mixed`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum TransactionOrHash {
+    Hash(String),
+    Full(Transaction),
+}
+
+pub enum TransactionSequenceIter<'a> {
+    BlockHashes(std::slice::Iter<'a, String>),
+    Transactions(std::slice::Iter<'a, Transaction>),
+    Mixed(std::slice::Iter<'a, TransactionOrHash>),
+}
+
+impl<'a> Iterator for TransactionSequenceIter<'a> {
+    type Item = TxOrHash<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            TransactionSequenceIter::BlockHashes(iter) => {
+                iter.next().map(|hash| TxOrHash::Hash(hash))
+            }
+            TransactionSequenceIter::Transactions(iter) => iter.next().map(TxOrHash::Full),
+            TransactionSequenceIter::Mixed(iter) => iter.next().map(TxOrHash::from),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            TransactionSequenceIter::BlockHashes(iter) => iter.size_hint(),
+            TransactionSequenceIter::Transactions(iter) => iter.size_hint(),
+            TransactionSequenceIter::Mixed(iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// Number of blocks after its validity start height during which a transaction can be included.
+pub const TRANSACTION_VALIDITY_WINDOW: u32 = 120;
+
+/// A transaction to be signed and sent by the node.
+///
+/// Nimiq accounts have no nonce or transaction count. A transaction is identified by its hash, which
+/// covers its content and its validity start height, and it can be included in any block from that
+/// height up to [`TRANSACTION_VALIDITY_WINDOW`] blocks later. Unless `validity_start_height` is set,
+/// the node uses its current block height as validity start height.
+///
+/// To send several transactions from one address:
+///
+/// * They are independent of each other and may be included in any order, so each must be valid on
+///   its own and the balance must cover all of them together.
+/// * Identical transactions sent within the same block have the same hash and only one of them is
+///   included. Vary the value, fee or `data`, or wait for the next block.
+/// * A transaction that was not included within the validity window expires and must be sent again.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct OutgoingTransaction {
     pub from: String,
     pub to: String,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub value: u64,
+    #[serde(deserialize_with = "de_u64_flexible::deserialize")]
     pub fee: u64,
     pub data: Option<String>,
+    /// Height of the first block the transaction can be included in. The node uses its current height if `None`.
+    #[serde(
+        default,
+        rename = "validityStartHeight",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub validity_start_height: Option<u32>,
+    /// Network the transaction is meant for. The node always signs for its own network, so
+    /// `send_transaction` refuses to send the transaction if the node is on a different one.
+    #[serde(default, rename = "networkId", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<Network>,
+}
+
+impl OutgoingTransaction {
+    pub fn value_coin(&self) -> Coin {
+        Coin(self.value)
+    }
+
+    pub fn fee_coin(&self) -> Coin {
+        Coin(self.fee)
+    }
+
+    /// Computes the hash the node assigns to this transaction once it is signed with the given validity
+    /// start height and network id (`42` on mainnet). Both accounts are assumed to be basic accounts.
+    ///
+    /// Returns `None` if an address or the hex encoded data is malformed.
+    pub fn content_hash(&self, validity_start_height: u32, network_id: u8) -> Option<String> {
+        use blake2::{digest::consts::U32, Blake2b, Digest};
+
+        let data = match &self.data {
+            Some(data) => hex::decode(data).ok()?,
+            None => Vec::new(),
+        };
+        let mut content = Vec::with_capacity(data.len() + 66);
+        content.extend_from_slice(&u16::try_from(data.len()).ok()?.to_be_bytes());
+        content.extend_from_slice(&data);
+        // Sender and recipient, each followed by its account type (basic).
+        content.extend_from_slice(&address_bytes(&self.from)?);
+        content.push(0);
+        content.extend_from_slice(&address_bytes(&self.to)?);
+        content.push(0);
+        content.extend_from_slice(&self.value.to_be_bytes());
+        content.extend_from_slice(&self.fee.to_be_bytes());
+        content.extend_from_slice(&validity_start_height.to_be_bytes());
+        content.push(network_id);
+        // Flags
+        content.push(0);
+        Some(hex::encode(Blake2b::<U32>::digest(&content)))
+    }
+}
+
+impl fmt::Display for OutgoingTransaction {
+    /// Formats a one-line summary, e.g. `NQ15 ... → NQ29 ...: 1.00000 NIM (fee 0.00138 NIM), memo: "rent"`.
+    /// Data that is not printable UTF-8 is shown as hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} → {}: {} (fee {})",
+            self.from,
+            self.to,
+            self.value_coin().format_with_unit(),
+            self.fee_coin().format_with_unit()
+        )?;
+        if let Some(data) = &self.data {
+            let memo = hex::decode(data)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .filter(|memo| !memo.chars().any(char::is_control));
+            match memo {
+                Some(memo) => write!(f, ", memo: {:?}", memo)?,
+                None => write!(f, ", data: {}", data)?,
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -259,3 +1583,49 @@ pub struct Wallet {
     pub address: String,
     pub public_key: String,
 }
+
+impl Wallet {
+    /// Parses the address of the wallet.
+    pub fn address_typed(&self) -> Result<Address, AddressError> {
+        self.address.parse()
+    }
+}
+
+impl TryFrom<&Wallet> for Address {
+    type Error = AddressError;
+
+    fn try_from(wallet: &Wallet) -> Result<Self, Self::Error> {
+        wallet.address_typed()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NodeStatus {
+    pub block_number: u32,
+    pub peer_count: u32,
+    pub consensus: String,
+    pub syncing: Syncing,
+    pub hashrate: f64,
+}
+
+/// Result of `Client::health`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The node answered the request.
+    pub reachable: bool,
+    pub consensus_established: bool,
+    /// The node is syncing, or its sync status is unknown.
+    pub syncing: bool,
+    pub peer_count: Option<u32>,
+    /// Round-trip time of the request, if the node answered.
+    pub latency: Option<std::time::Duration>,
+    /// Errors of the request or of single checks.
+    pub errors: Vec<String>,
+}
+
+impl HealthReport {
+    /// Returns `true` if the node is reachable, has established consensus and is not syncing.
+    pub fn is_ready(&self) -> bool {
+        self.reachable && self.consensus_established && !self.syncing
+    }
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use nimiq_rpc::blocking::BlockingClient;
+use serde_json::json;
+
+#[test]
+fn calls_block_until_answered() {
+    // The mock node needs a runtime of its own, the client brings its own.
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let url = runtime.block_on(common::mock_node(|method, _| match method {
+        "blockNumber" => Ok(json!(76415)),
+        "consensus" => Ok(json!("established")),
+        _ => common::method_not_found(),
+    }));
+    let client = BlockingClient::new(url);
+    assert_eq!(client.block_number().unwrap(), 76415);
+    assert_eq!(client.consensus().unwrap(), "established");
+    assert!(client.peer_count().is_err());
+}
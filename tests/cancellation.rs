@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use nimiq_rpc::{Client, NimiqError};
+use tokio::{io::AsyncReadExt, net::TcpListener};
+use url::Url;
+
+#[tokio::test]
+async fn dropped_call_closes_connection() {
+    // A server that accepts the request but never answers it.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 4096];
+        // Drain the request, then wait for the client to hang up.
+        while socket.read(&mut buf).await.unwrap() > 0 {}
+    });
+
+    let client = Client::new(url);
+    tokio::select! {
+        _ = client.block_number() => panic!("The server never answers"),
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    }
+
+    // The connection must be closed once the in-flight call is dropped.
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("Connection was not closed after cancellation")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn get_work_timeout_gives_up() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = [0u8; 4096];
+        while socket.read(&mut buf).await.unwrap() > 0 {}
+    });
+
+    let client = Client::new(url);
+    assert!(matches!(
+        client.get_work_timeout(Duration::from_millis(100)).await,
+        Err(NimiqError::Timeout(timeout)) if timeout == Duration::from_millis(100)
+    ));
+}
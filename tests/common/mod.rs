@@ -0,0 +1,191 @@
+//! A minimal JSON-RPC node that answers requests with a handler, for tests that do not need a real node.
+
+#![allow(dead_code)]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+use url::Url;
+
+/// Result of a mocked call: the `result` value, or the `code` and `message` of an error.
+pub type Response = Result<Value, (i32, String)>;
+
+/// Headers of the HTTP requests received by a mock node, in lowercase.
+pub type Headers = Arc<Mutex<Vec<(String, String)>>>;
+
+/// JSON bodies of the HTTP requests received by a mock node.
+pub type Requests = Arc<Mutex<Vec<Value>>>;
+
+/// Number of HTTP requests a mock node is answering at the same time.
+#[derive(Debug, Default)]
+pub struct InFlight {
+    current: AtomicUsize,
+    max: AtomicUsize,
+}
+
+impl InFlight {
+    /// The highest number of requests answered at the same time so far.
+    pub fn max(&self) -> usize {
+        self.max.load(Ordering::SeqCst)
+    }
+}
+
+/// Starts a mock node on a random local port and returns its URL.
+/// `handler` receives the method name and the params of every call.
+pub async fn mock_node<F>(handler: F) -> Url
+where
+    F: Fn(&str, &Value) -> Response + Send + Sync + 'static,
+{
+    mock_node_with_headers(handler).await.0
+}
+
+/// Like `mock_node`, but also records the headers of every HTTP request.
+pub async fn mock_node_with_headers<F>(handler: F) -> (Url, Headers)
+where
+    F: Fn(&str, &Value) -> Response + Send + Sync + 'static,
+{
+    let (url, headers, _) = start(handler, Duration::ZERO, Arc::default()).await;
+    (url, headers)
+}
+
+/// Like `mock_node`, but also records the JSON body of every HTTP request.
+pub async fn mock_node_with_requests<F>(handler: F) -> (Url, Requests)
+where
+    F: Fn(&str, &Value) -> Response + Send + Sync + 'static,
+{
+    let (url, _, requests) = start(handler, Duration::ZERO, Arc::default()).await;
+    (url, requests)
+}
+
+/// Like `mock_node`, but answers every HTTP request only after `delay`, and counts the requests in flight.
+pub async fn slow_mock_node<F>(delay: Duration, handler: F) -> (Url, Arc<InFlight>)
+where
+    F: Fn(&str, &Value) -> Response + Send + Sync + 'static,
+{
+    let in_flight = Arc::<InFlight>::default();
+    let (url, _, _) = start(handler, delay, in_flight.clone()).await;
+    (url, in_flight)
+}
+
+async fn start<F>(handler: F, delay: Duration, in_flight: Arc<InFlight>) -> (Url, Headers, Requests)
+where
+    F: Fn(&str, &Value) -> Response + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+    let handler = Arc::new(handler);
+    let headers = Headers::default();
+    let requests = Requests::default();
+    let (recorded_headers, recorded_requests) = (headers.clone(), requests.clone());
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(handle(
+                socket,
+                handler.clone(),
+                recorded_headers.clone(),
+                recorded_requests.clone(),
+                delay,
+                in_flight.clone(),
+            ));
+        }
+    });
+    (url, headers, requests)
+}
+
+async fn handle<F>(
+    socket: TcpStream,
+    handler: Arc<F>,
+    headers: Headers,
+    requests: Requests,
+    delay: Duration,
+    in_flight: Arc<InFlight>,
+) where
+    F: Fn(&str, &Value) -> Response,
+{
+    let mut reader = BufReader::new(socket);
+    loop {
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                let name = name.to_ascii_lowercase();
+                if name == "content-length" {
+                    content_length = value.trim().parse().unwrap();
+                }
+                headers
+                    .lock()
+                    .unwrap()
+                    .push((name, value.trim().to_string()));
+            }
+        }
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body).await.unwrap();
+        if !delay.is_zero() {
+            let current = in_flight.current.fetch_add(1, Ordering::SeqCst) + 1;
+            in_flight.max.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(delay).await;
+            in_flight.current.fetch_sub(1, Ordering::SeqCst);
+        }
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        requests.lock().unwrap().push(request.clone());
+        let response = match request {
+            Value::Array(calls) => {
+                Value::Array(calls.iter().map(|c| answer(c, &*handler)).collect())
+            }
+            call => answer(&call, &*handler),
+        }
+        .to_string();
+        let message = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        if reader
+            .get_mut()
+            .write_all(message.as_bytes())
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+fn answer<F>(call: &Value, handler: &F) -> Value
+where
+    F: Fn(&str, &Value) -> Response,
+{
+    let method = call["method"].as_str().unwrap_or_default();
+    let params = call.get("params").cloned().unwrap_or(Value::Null);
+    match handler(method, &params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": call["id"], "result": result }),
+        Err((code, message)) => json!({
+            "jsonrpc": "2.0",
+            "id": call["id"],
+            "error": { "code": code, "message": message },
+        }),
+    }
+}
+
+/// Error returned by nodes for methods they do not know.
+pub fn method_not_found() -> Response {
+    Err((-32601, "Method not found".to_string()))
+}
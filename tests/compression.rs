@@ -0,0 +1,89 @@
+use std::io::Write;
+
+use flate2::{write::GzEncoder, Compression};
+use nimiq_rpc::ClientBuilder;
+use serde_json::Value;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    sync::oneshot,
+};
+use url::Url;
+
+/// Answers a single `blockNumber` call, gzip-compressing the response if the client accepts it.
+/// Reports the `accept-encoding` header the client sent.
+async fn gzip_node() -> (Url, oneshot::Receiver<Option<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+    let (sender, receiver) = oneshot::channel();
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut reader = BufReader::new(socket);
+        let mut content_length = 0;
+        let mut accept_encoding = None;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).await.unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                } else if name.eq_ignore_ascii_case("accept-encoding") {
+                    accept_encoding = Some(value.trim().to_string());
+                }
+            }
+        }
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body).await.unwrap();
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        let response = format!(
+            r#"{{"jsonrpc":"2.0","id":{},"result":882418}}"#,
+            request["id"]
+        );
+
+        let gzip = accept_encoding
+            .as_deref()
+            .is_some_and(|value| value.contains("gzip"));
+        let (encoding, payload) = if gzip {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(response.as_bytes()).unwrap();
+            ("content-encoding: gzip\r\n", encoder.finish().unwrap())
+        } else {
+            ("", response.into_bytes())
+        };
+        let head = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n{}content-length: {}\r\n\r\n",
+            encoding,
+            payload.len()
+        );
+        let socket = reader.get_mut();
+        socket.write_all(head.as_bytes()).await.unwrap();
+        socket.write_all(&payload).await.unwrap();
+        sender.send(accept_encoding).unwrap();
+    });
+    (url, receiver)
+}
+
+#[tokio::test]
+async fn decompresses_gzip_responses() {
+    let (url, accept_encoding) = gzip_node().await;
+    let client = ClientBuilder::new()
+        .accept_encoding(true)
+        .build(url)
+        .unwrap();
+    assert_eq!(client.block_number().await.unwrap(), 882418);
+    let accept_encoding = accept_encoding.await.unwrap().unwrap();
+    assert!(accept_encoding.contains("gzip"));
+    assert!(accept_encoding.contains("deflate"));
+}
+
+#[tokio::test]
+async fn compression_disabled_by_default() {
+    let (url, accept_encoding) = gzip_node().await;
+    let client = ClientBuilder::new().build(url).unwrap();
+    assert_eq!(client.block_number().await.unwrap(), 882418);
+    assert_eq!(accept_encoding.await.unwrap(), None);
+}
@@ -0,0 +1,69 @@
+use nimiq_rpc::difficulty::*;
+
+/// Returns the big-endian 256-bit target 2^`exponent`.
+fn power_of_two(exponent: usize) -> [u8; 32] {
+    let mut target = [0u8; 32];
+    target[31 - exponent / 8] = 1 << (exponent % 8);
+    target
+}
+
+#[test]
+fn nbits_to_target_vectors() {
+    assert_eq!(nbits_to_target(MAX_TARGET_NBITS), power_of_two(240));
+    assert_eq!(nbits_to_target(0x1e010000), power_of_two(232));
+    assert_eq!(nbits_to_target(0x1f008000), power_of_two(239));
+    assert_eq!(nbits_to_target(0), [0u8; 32]);
+}
+
+#[test]
+fn target_to_nbits_vectors() {
+    assert_eq!(target_to_nbits(&power_of_two(240)), MAX_TARGET_NBITS);
+    assert_eq!(target_to_nbits(&power_of_two(232)), 0x1e010000);
+    // A mantissa with the high bit set takes one more byte.
+    assert_eq!(target_to_nbits(&power_of_two(239)), 0x1f008000);
+    assert_eq!(target_to_nbits(&[0u8; 32]), 0);
+    for nbits in [0x1d00ffff, 0x1f00f000, 0x1c7fffff] {
+        assert_eq!(target_to_nbits(&nbits_to_target(nbits)), nbits);
+    }
+}
+
+#[test]
+fn difficulty_vectors() {
+    assert_eq!(target_to_difficulty(&power_of_two(240)), 1.0);
+    assert_eq!(target_to_difficulty(&power_of_two(232)), 256.0);
+    assert_eq!(target_to_difficulty(&power_of_two(241)), 0.5);
+    assert_eq!(target_to_difficulty(&[0u8; 32]), f64::INFINITY);
+
+    assert_eq!(difficulty_to_target(1.0), power_of_two(240));
+    assert_eq!(difficulty_to_target(256.0), power_of_two(232));
+    assert_eq!(difficulty_to_target(0.5), power_of_two(241));
+    assert_eq!(difficulty_to_target(0.0), [0xff; 32]);
+
+    let target = difficulty_to_target(224356.25830258);
+    let difficulty = target_to_difficulty(&target);
+    assert!((difficulty - 224356.25830258).abs() < 1e-6);
+}
+
+#[test]
+fn accessors() {
+    use nimiq_rpc::primitives::{GetWork, Header};
+
+    let header = Header {
+        version: 1,
+        prev_hash: String::new(),
+        interlink_hash: String::new(),
+        accounts_hash: String::new(),
+        n_bits: 0x1e010000,
+        height: 1,
+    };
+    assert_eq!(header.target(), power_of_two(232));
+    assert_eq!(header.difficulty(), 256.0);
+
+    let work = GetWork {
+        data: String::new(),
+        suffix: String::new(),
+        target: u64::from(MAX_TARGET_NBITS),
+        algorithm: String::new(),
+    };
+    assert_eq!(work.pool_difficulty(), 1.0);
+}
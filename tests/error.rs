@@ -0,0 +1,169 @@
+mod common;
+
+use nimiq_rpc::{primitives::BlockId, Client, NimiqError};
+use serde_json::json;
+
+#[tokio::test]
+async fn accounts_empty() {
+    let url = common::mock_node(|_, _| Ok(json!([]))).await;
+    let client = Client::new(url);
+    assert!(client.accounts().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn accounts_method_not_found() {
+    let url = common::mock_node(|_, _| common::method_not_found()).await;
+    let client = Client::new(url);
+    match client.accounts().await {
+        Err(NimiqError::MethodNotFound(method)) => assert_eq!(method, "accounts"),
+        other => panic!("Expected MethodNotFound, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn other_errors_are_rpc_errors() {
+    let url = common::mock_node(|_, _| Err((-32603, "Internal error".to_string()))).await;
+    let client = Client::new(url);
+    assert!(matches!(client.accounts().await, Err(NimiqError::Rpc(_))));
+}
+
+#[tokio::test]
+async fn get_block_by_number_zero() {
+    let url = common::mock_node(|_, _| panic!("Block 0 must not be requested")).await;
+    let client = Client::new(url);
+    assert!(matches!(
+        client.get_block_by_number(0, false).await,
+        Err(NimiqError::InvalidArgument(_))
+    ));
+}
+
+#[tokio::test]
+async fn get_block_number_zero() {
+    let url = common::mock_node(|_, _| panic!("Block 0 must not be requested")).await;
+    let client = Client::new(url);
+    assert!(matches!(
+        client.get_block(BlockId::Number(0), false).await,
+        Err(NimiqError::InvalidArgument(_))
+    ));
+}
+
+#[tokio::test]
+async fn get_block_by_number_zero_with_genesis_at_zero() {
+    let (url, requests) = common::mock_node_with_requests(|_, _| Ok(serde_json::json!(null))).await;
+    let client = Client::new(url).with_genesis_block_number(0);
+    // The node is asked, and has no block to return in this mock.
+    assert!(client.get_block_by_number(0, false).await.is_err());
+    assert_eq!(requests.lock().unwrap()[0]["params"][0], 0);
+}
+
+#[tokio::test]
+async fn mempool_hashes_rejects_malformed_hash() {
+    let url = common::mock_node(|_, _| Ok(serde_json::json!(["not a hash"]))).await;
+    let client = Client::new(url);
+    assert!(matches!(
+        client.mempool_hashes().await,
+        Err(NimiqError::InvalidResponse(_))
+    ));
+}
+
+#[tokio::test]
+async fn peer_state_rejects_malformed_peer_address() {
+    let url = common::mock_node(|_, _| panic!("Malformed addresses must not be sent")).await;
+    let client = Client::new(url);
+    for address in [
+        "urp.best:8443/a400c3825edb8e00f1d99dea5299bce8",
+        "https://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8",
+        "wss://urp.best/a400c3825edb8e00f1d99dea5299bce8",
+        "wss://urp.best:8443",
+        "wss://[::1]/a400c3825edb8e00f1d99dea5299bce8",
+        "wss://urp.best:8443/",
+    ] {
+        assert!(
+            matches!(
+                client.peer_state(address).await,
+                Err(NimiqError::InvalidArgument(_))
+            ),
+            "{}",
+            address
+        );
+    }
+}
+
+#[tokio::test]
+async fn peer_state_accepts_websocket_peer_address() {
+    let url = common::mock_node(|_, params| {
+        Ok(serde_json::json!({
+            "id": "a400c3825edb8e00f1d99dea5299bce8",
+            "address": params[0],
+            "addressState": 2
+        }))
+    })
+    .await;
+    let client = Client::new(url);
+    for address in [
+        "wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8",
+        "wss://urp.best:443/a400c3825edb8e00f1d99dea5299bce8",
+        "ws://127.0.0.1:8080/a400c3825edb8e00f1d99dea5299bce8",
+        "wss://[::1]:8443/a400c3825edb8e00f1d99dea5299bce8",
+    ] {
+        assert_eq!(client.peer_state(address).await.unwrap().address, address);
+    }
+}
+
+fn block(hash: &str) -> serde_json::Value {
+    json!({
+        "number": 882418,
+        "hash": hash,
+        "pow": "00000000000d6b1bc3a5d8c05c5b7fb4d6e2dbbfd2fa52a4a52ec6bd6a58e5e8",
+        "parentHash": "e2ff2a3b3d3cd9bc6ef72cf3d0ba59f3a0a7e0ae5db0c1bd8bda3bba0a1c96f9",
+        "nonce": 1146518856,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "fbbb94d1fbd1d3d3a7ed2a1d1c47a0b0e2f5e8c4",
+        "minerAddress": "NQ36 YEXR 9LFT SF9P 79YD 58EH 8HX0 N3HF BT64",
+        "difficulty": "224356.25830258",
+        "extraData": "",
+        "size": 576,
+        "timestamp": 1571844434,
+        "transactions": []
+    })
+}
+
+#[tokio::test]
+async fn get_block_by_hash_verified() {
+    const HASH: &str = "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6";
+    let calls = std::sync::atomic::AtomicU32::new(0);
+    let url = common::mock_node(move |_, params| {
+        // Hashes are sent in lowercase without prefix.
+        assert_eq!(params[0], HASH);
+        if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+            Ok(block(&format!("0x{}", HASH.to_uppercase())))
+        } else {
+            // A proxy answering with a cached block.
+            Ok(block(
+                "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+            ))
+        }
+    })
+    .await;
+    let client = Client::new(url);
+    let requested = format!("0x{}", HASH.to_uppercase());
+    assert_eq!(
+        client
+            .get_block_by_hash_verified(&requested, false)
+            .await
+            .unwrap()
+            .hash,
+        HASH
+    );
+    match client.get_block_by_hash_verified(HASH, false).await {
+        Err(NimiqError::HashMismatch { expected, actual }) => {
+            assert_eq!(expected, HASH);
+            assert_eq!(
+                actual,
+                "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
+            );
+        }
+        other => panic!("Expected a hash mismatch, got {:?}", other),
+    }
+}
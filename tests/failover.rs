@@ -0,0 +1,108 @@
+mod common;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+
+use nimiq_rpc::{primitives::OutgoingTransaction, Client, FailoverClient};
+use serde_json::json;
+use url::Url;
+
+/// A URL no node listens on.
+fn dead_node() -> Url {
+    Url::parse("http://127.0.0.1:1").unwrap()
+}
+
+#[tokio::test]
+async fn reads_fail_over_and_remember_the_last_good_node() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let counted = calls.clone();
+    let url = common::mock_node(move |_, _| {
+        counted.fetch_add(1, Ordering::SeqCst);
+        Ok(json!(42))
+    })
+    .await;
+    let failing = common::mock_node(|_, _| Err((1, "Not synced".to_string()))).await;
+    let client = FailoverClient::new(vec![
+        Client::new(dead_node()),
+        Client::new(failing),
+        Client::new(url),
+    ]);
+    assert_eq!(client.block_number().await.unwrap(), 42);
+    assert_eq!(client.block_number().await.unwrap(), 42);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn reads_return_the_last_error() {
+    let client = FailoverClient::new(vec![Client::new(dead_node()), Client::new(dead_node())]);
+    assert!(client.block_number().await.is_err());
+}
+
+#[tokio::test]
+async fn writes_are_broadcast() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let mut clients = vec![Client::new(dead_node())];
+    for _ in 0..3 {
+        let counted = calls.clone();
+        let url = common::mock_node(move |method, _| {
+            assert_eq!(method, "sendRawTransaction");
+            counted.fetch_add(1, Ordering::SeqCst);
+            Ok(json!(
+                "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+            ))
+        })
+        .await;
+        clients.push(Client::new(url));
+    }
+    let client = FailoverClient::new(clients);
+    assert_eq!(
+        client.send_raw_transaction("00").await.unwrap(),
+        "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    let client = client.with_broadcast_limit(2);
+    client.send_raw_transaction("00").await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn send_transaction_signs_once_and_broadcasts_the_signed_transaction() {
+    let signed = Arc::new(AtomicU32::new(0));
+    let sent = Arc::new(AtomicU32::new(0));
+    let mut clients = vec![Client::new(dead_node())];
+    for _ in 0..3 {
+        let (signed, sent) = (signed.clone(), sent.clone());
+        let url = common::mock_node(move |method, params| match method {
+            "createRawTransaction" => {
+                signed.fetch_add(1, Ordering::SeqCst);
+                Ok(json!("0100"))
+            }
+            "sendRawTransaction" => {
+                assert_eq!(params[0], "0100");
+                sent.fetch_add(1, Ordering::SeqCst);
+                Ok(json!(
+                    "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+                ))
+            }
+            method => panic!("unexpected method {}", method),
+        })
+        .await;
+        clients.push(Client::new(url));
+    }
+    let client = FailoverClient::new(clients);
+    let tx = OutgoingTransaction {
+        from: "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+        to: "824aa01033c89595479bab9d8deb4fc9f90e1ebf".to_string(),
+        value: 100,
+        fee: 0,
+        data: None,
+        validity_start_height: None,
+        network_id: None,
+    };
+    client.send_transaction(&tx).await.unwrap();
+    assert_eq!(signed.load(Ordering::SeqCst), 1);
+    assert_eq!(sent.load(Ordering::SeqCst), 3);
+}
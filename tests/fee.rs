@@ -0,0 +1,63 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use nimiq_rpc::fee::{FeeEstimator, FeePriority};
+use nimiq_rpc::Client;
+use serde_json::json;
+
+#[tokio::test]
+async fn suggest_fee_from_mempool_buckets() {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let counter = fetches.clone();
+    let url = common::mock_node(move |method, _| match method {
+        "mempool" => {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(json!({
+                "total": 10,
+                "buckets": [10, 2, 0],
+                "10": 2,
+                "2": 3,
+                "0": 5,
+            }))
+        }
+        "minFeePerByte" => Ok(json!(1)),
+        _ => common::method_not_found(),
+    })
+    .await;
+    let estimator = FeeEstimator::new(Client::new(url));
+
+    assert_eq!(
+        estimator.suggest_fee(138, FeePriority::Low).await.unwrap(),
+        138
+    );
+    assert_eq!(
+        estimator
+            .suggest_fee(138, FeePriority::Medium)
+            .await
+            .unwrap(),
+        276
+    );
+    assert_eq!(
+        estimator.suggest_fee(138, FeePriority::High).await.unwrap(),
+        1380
+    );
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn suggest_fee_falls_back_to_min_fee() {
+    let url = common::mock_node(|method, _| match method {
+        "mempool" => Ok(json!({ "total": 0, "buckets": [] })),
+        "minFeePerByte" => Ok(json!(2)),
+        _ => common::method_not_found(),
+    })
+    .await;
+    let estimator = FeeEstimator::new(Client::new(url));
+
+    assert_eq!(
+        estimator.suggest_fee(138, FeePriority::High).await.unwrap(),
+        276
+    );
+}
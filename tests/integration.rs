@@ -1,44 +1,73 @@
+//! Tests against a real node, e.g. a local development node or one started in CI:
+//!
+//! ```sh
+//! NIMIQ_RPC_URL=http://127.0.0.1:8648 cargo test --test integration
+//! ```
+//!
+//! Without `NIMIQ_RPC_URL`, every test is skipped. Some assertions expect mainnet data.
+//!
+//! Tests that mine blocks only run if `NIMIQ_RPC_MINING` is set as well, and need a development node
+//! whose difficulty is low enough to find a block on the CPU within seconds.
+
 #[cfg(test)]
 mod tests {
     use nimiq_rpc::*;
     use url::Url;
 
-    fn client() -> Client {
-        let host = "http://seed-host.com:8648";
+    /// Environment variable holding the URL of the node to test against.
+    const URL_VAR: &str = "NIMIQ_RPC_URL";
+
+    /// Environment variable enabling tests that mine blocks on the node.
+    const MINING_VAR: &str = "NIMIQ_RPC_MINING";
 
-        if host == "http://seed-host.com:8648" || host == "http://seed-host.com:8648/" {
-            panic!("You have to change the host to your RPC server in the tests!")
+    /// Returns a client for the node given in `NIMIQ_RPC_URL`, or `None` if it is not set.
+    fn client() -> Option<Client> {
+        match std::env::var(URL_VAR) {
+            Ok(url) => Some(Client::new(Url::parse(&url).unwrap())),
+            Err(_) => {
+                eprintln!("{} is not set, skipping", URL_VAR);
+                None
+            }
         }
-        Client::new(Url::parse(host).unwrap())
     }
 
     #[tokio::test]
     async fn accounts() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.accounts().await.unwrap();
     }
 
     #[tokio::test]
     async fn block_number() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.block_number().await.unwrap();
     }
 
     #[tokio::test]
     async fn consensus() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(client.consensus().await.unwrap(), "established");
     }
 
     #[tokio::test]
     async fn create_account() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.create_account().await.unwrap();
     }
 
     #[tokio::test]
     async fn get_account() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client
             .get_account("NQ07 0000 0000 0000 0000 0000 0000 0000 0000")
             .await
@@ -47,7 +76,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_accounts_tree_chunk() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client
             .get_accounts_tree_chunk(
                 "A9284B441B56E93DE62F557414CC9B850BAD2BD30CF84B013CFE2EF6E11B6DA6",
@@ -59,7 +90,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_block_by_hash() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_block_by_hash(
@@ -75,7 +108,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_block_and_tx_by_hash() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_block_by_hash(
@@ -91,7 +126,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_block_by_number() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_block_by_number(882418, false)
@@ -104,22 +141,62 @@ mod tests {
 
     #[tokio::test]
     async fn get_block_and_tx_by_number() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client.get_block_by_number(882418, true).await.unwrap().hash,
             "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6"
         );
     }
 
+    #[tokio::test]
+    async fn get_block_summary_by_number() {
+        let Some(client) = client() else {
+            return;
+        };
+        let summary = client.get_block_summary_by_number(882418).await.unwrap();
+        assert_eq!(
+            summary.hash,
+            "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6"
+        );
+        assert_eq!(summary.transaction_count, 2);
+    }
+
+    #[tokio::test]
+    async fn get_block() {
+        let Some(client) = client() else {
+            return;
+        };
+        assert_eq!(
+            client
+                .get_block(primitives::BlockId::Number(882418), false)
+                .await
+                .unwrap()
+                .unwrap()
+                .hash,
+            "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6"
+        );
+        assert!(client
+            .get_block(primitives::BlockId::Latest, false)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
     #[tokio::test]
     async fn get_block_template() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.get_block_template().await.unwrap();
     }
 
     #[tokio::test]
     async fn get_block_transaction_count_by_hash() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_block_transaction_count_by_hash(
@@ -133,7 +210,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_block_transaction_count_by_number() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_block_transaction_count_by_number(882418)
@@ -143,9 +222,30 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn preview_transaction() {
+        let Some(client) = client() else {
+            return;
+        };
+        let tx = primitives::OutgoingTransaction {
+            from: "NQ32 R6DB VFM5 M931 7X4E 0N5Q LJ56 9QCR 4T42".to_string(),
+            to: "NQ74 61S8 2FD3 RVPG HU09 1Y57 77E6 BL38 TQH3".to_string(),
+            value: 100,
+            fee: 0,
+            data: None,
+            validity_start_height: None,
+            network_id: None,
+        };
+        let details = client.preview_transaction(&tx).await.unwrap();
+        assert_eq!(details.value, 100);
+        assert_eq!(details.to_address, tx.to);
+    }
+
     #[tokio::test]
     async fn get_transaction_by_block_hash_and_index() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_transaction_by_block_hash_and_index(
@@ -161,7 +261,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_transaction_by_block_number_and_index() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_transaction_by_block_number_and_index(76415, 20)
@@ -174,7 +276,9 @@ mod tests {
 
     #[tokio::test]
     async fn get_transaction_by_hash() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_transaction_by_hash(
@@ -182,14 +286,17 @@ mod tests {
                 )
                 .await
                 .unwrap()
-                .block_hash,
+                .block_hash
+                .unwrap(),
             "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
         );
     }
 
     #[tokio::test]
     async fn get_transaction_by_hash_2() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_transaction_by_hash_2(
@@ -197,14 +304,17 @@ mod tests {
                 )
                 .await
                 .unwrap()
-                .block_hash,
+                .block_hash
+                .unwrap(),
             "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
         );
     }
 
     #[tokio::test]
     async fn get_transaction_receipt() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(
             client
                 .get_transaction_receipt(
@@ -219,88 +329,192 @@ mod tests {
 
     #[tokio::test]
     async fn get_transactions_by_address() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client
             .get_transactions_by_address("NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN", 5)
             .await
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn get_transactions_by_address_filtered() {
+        let Some(client) = client() else {
+            return;
+        };
+        let address = "NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN";
+        let transactions = client
+            .get_transactions_by_address_filtered(address, 5, primitives::Direction::Received)
+            .await
+            .unwrap();
+        assert!(transactions
+            .iter()
+            .all(|tx| primitives::Direction::Received.matches(tx, address)));
+    }
+
+    #[tokio::test]
+    async fn get_transactions_by_address_since() {
+        let Some(client) = client() else {
+            return;
+        };
+        let transactions = client
+            .get_transactions_by_address_since(
+                "NQ69 9A4A MB83 HXDQ 4J46 BH5R 4JFF QMA9 C3GN",
+                1528297445,
+                5,
+            )
+            .await
+            .unwrap();
+        assert!(transactions
+            .iter()
+            .all(|tx| tx.timestamp.unwrap() > 1528297445));
+    }
+
+    /// Returns a client for tests that mine blocks, or `None` if they are not enabled.
+    fn mining_client() -> Option<Client> {
+        if std::env::var(MINING_VAR).is_err() {
+            eprintln!("{} is not set, skipping", MINING_VAR);
+            return None;
+        }
+        client()
+    }
+
+    /// Mines a block on the node with `get_work` and `submit_block` and returns its hash.
+    /// Fetches fresh work after every 10 000 nonces and gives up after 100 rounds.
+    async fn mine_block(client: &Client) -> String {
+        for _ in 0..100 {
+            if let Some(hash) = client.mine_once(10_000).await.unwrap() {
+                return hash;
+            }
+        }
+        panic!("No block found, is the difficulty of the node low enough?")
+    }
+
+    #[tokio::test]
+    async fn mine_block_round_trip() {
+        let Some(client) = mining_client() else {
+            return;
+        };
+        let height = client.block_number().await.unwrap();
+        let hash = mine_block(&client).await;
+        assert_eq!(client.block_number().await.unwrap(), height + 1);
+        assert_eq!(
+            client.get_block_by_hash(&hash, false).await.unwrap().number,
+            height + 1
+        );
+    }
+
     #[tokio::test]
     async fn get_work() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.get_work().await.unwrap();
     }
 
     #[tokio::test]
     async fn hashrate() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.hashrate().await.unwrap();
     }
 
     #[tokio::test]
     async fn log() {
-        let client = client();
-        assert_eq!(client.log("*", "log").await.unwrap(), true);
+        let Some(client) = client() else {
+            return;
+        };
+        assert!(client.log("*", "log").await.unwrap());
     }
 
     #[tokio::test]
     async fn mempool_content() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.mempool_content().await.unwrap();
     }
 
     #[tokio::test]
     async fn miner_address() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.miner_address().await.unwrap();
     }
 
     #[tokio::test]
     async fn miner_threads() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.miner_threads().await.unwrap();
     }
 
     #[tokio::test]
     async fn miner_threads_with_update() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(client.miner_threads_with_update(1).await.unwrap(), 1);
     }
 
     #[tokio::test]
     async fn min_fee_per_byte() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.min_fee_per_byte().await.unwrap();
     }
 
     #[tokio::test]
     async fn min_fee_per_byte_with_update() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         assert_eq!(client.min_fee_per_byte_with_update(1).await.unwrap(), 1);
     }
 
     #[tokio::test]
     async fn mining() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.mining().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn node_status() {
+        let Some(client) = client() else {
+            return;
+        };
+        let status = client.node_status().await.unwrap();
+        assert_eq!(status.consensus, "established");
+    }
+
     #[tokio::test]
     async fn peer_count() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.peer_count().await.unwrap();
     }
 
     #[tokio::test]
     async fn peer_list() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.peer_list().await.unwrap();
     }
 
     #[tokio::test]
     async fn peer_state() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client
             .peer_state("wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8")
             .await
@@ -309,7 +523,9 @@ mod tests {
 
     #[tokio::test]
     async fn peer_state_with_update() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client
             .peer_state_with_update(
                 "wss://urp.best:8443/a400c3825edb8e00f1d99dea5299bce8",
@@ -321,29 +537,37 @@ mod tests {
 
     #[tokio::test]
     async fn pool_confirmed_balance() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.pool_confirmed_balance().await.unwrap();
     }
 
     #[tokio::test]
     async fn pool_connection_state() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         client.pool_connection_state().await.unwrap();
     }
 
     #[tokio::test]
     async fn syncing() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         let state = client.syncing().await.unwrap();
         match state {
-            primitives::Syncing::IsSyncing(result) => assert_eq!(result, false),
-            primitives::Syncing::Pending(_) => assert!(true),
+            primitives::Syncing::IsSyncing(result) => assert!(!result),
+            primitives::Syncing::Pending(_) => {}
         }
     }
 
     #[tokio::test]
     async fn constant() {
-        let client = client();
+        let Some(client) = client() else {
+            return;
+        };
         let constant = client
             .get_constant("BaseConsensusAgent.FREE_TRANSACTIONS_PER_SECOND")
             .await
@@ -0,0 +1,94 @@
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use nimiq_rpc::merkle::*;
+use nimiq_rpc::primitives::Block;
+
+/// Blake2b hashes of the single bytes `0` to `4`.
+fn leaves() -> Vec<String> {
+    [
+        "03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314",
+        "ee155ace9c40292074cb6aff8c9ccdd273c81648ff1149ef36bcea6ebb8a3e25",
+        "bb30a42c1e62f0afda5f0a4e8a562f7a13a24cea00ee81917b86b89e801314aa",
+        "e88bd757ad5b9bedf372d8d3f0cf6c962a469db61a265f6418e1ffed86da29ec",
+        "642206314f534b29ad297d82440a5f9f210e30ca5ced805a587ca402de927342",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+const ROOT: &str = "cfd113df11628bd8679f3218b853844c9ca404e5599e1c4f4c9b7549e7f92670";
+
+#[test]
+fn merkle_root_vectors() {
+    let leaves = leaves();
+    assert_eq!(merkle_root(&leaves).unwrap(), ROOT);
+    // Odd lengths put the larger half on the left.
+    assert_eq!(
+        merkle_root(&leaves[..3]).unwrap(),
+        "3f8fc66b1fc7006260eb087b7745362cd8d64b9983d89ad0281f03e8dc492464"
+    );
+    assert_eq!(merkle_root(&leaves[..1]).unwrap(), leaves[0]);
+    assert_eq!(
+        merkle_root(&[]).unwrap(),
+        "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+    );
+    assert_eq!(merkle_root(&["cafe".to_string()]), None);
+}
+
+#[test]
+fn merkle_path_verifies_every_leaf() {
+    let leaves = leaves();
+    for (index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_path(&leaves, index).unwrap();
+        assert!(verify_merkle_proof(leaf, &path, ROOT));
+        assert!(!verify_merkle_proof(&leaves[(index + 1) % 5], &path, ROOT));
+    }
+    assert_eq!(merkle_path(&leaves, 5), None);
+}
+
+#[test]
+fn verify_rejects_tampered_proofs() {
+    let leaves = leaves();
+    let mut path = merkle_path(&leaves, 3).unwrap();
+    path[0].left = !path[0].left;
+    assert!(!verify_merkle_proof(&leaves[3], &path, ROOT));
+    path[0].left = !path[0].left;
+    path[1].hash = "zz".to_string();
+    assert!(!verify_merkle_proof(&leaves[3], &path, ROOT));
+}
+
+#[test]
+fn body_hash_from_block_transactions() {
+    // A PoW block without pruned accounts, mined by ad25610f… with extra data "nimiq". The body hash was
+    // computed with an independent Blake2b implementation.
+    let block: Block = serde_json::from_str(
+        r#"{
+            "number": 1234,
+            "hash": "0000000000000000000000000000000000000000000000000000000000001234",
+            "parentHash": "0000000000000000000000000000000000000000000000000000000000001233",
+            "nonce": 0,
+            "bodyHash": "4ef20588155b0ef4398842fd44162f68d805462a2df8f65dc24a2675f1e2987f",
+            "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+            "miner": "ad25610feb43d75307763d3f010822a757027429",
+            "minerAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "difficulty": "1",
+            "extraData": "6e696d6971",
+            "size": 146,
+            "timestamp": 1528297445,
+            "transactions": [
+                "03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314",
+                "ee155ace9c40292074cb6aff8c9ccdd273c81648ff1149ef36bcea6ebb8a3e25",
+                "bb30a42c1e62f0afda5f0a4e8a562f7a13a24cea00ee81917b86b89e801314aa"
+            ]
+        }"#,
+    )
+    .unwrap();
+    let hash = |hex: &str| hex::encode(Blake2b::<U32>::digest(hex::decode(hex).unwrap()));
+    let mut leaves = vec![hash(&block.miner), hash(&block.extra_data)];
+    leaves.extend(block.transactions.iter().map(|tx| tx.hash().to_string()));
+
+    assert_eq!(merkle_root(&leaves).unwrap(), block.body_hash);
+    for index in 2..leaves.len() {
+        let path = merkle_path(&leaves, index).unwrap();
+        assert!(verify_merkle_proof(&leaves[index], &path, &block.body_hash));
+    }
+}
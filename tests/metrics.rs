@@ -0,0 +1,40 @@
+mod common;
+
+use nimiq_rpc::Client;
+use serde_json::json;
+
+#[tokio::test]
+async fn metrics_count_calls_per_method() {
+    let url = common::mock_node(|method, _| match method {
+        "blockNumber" => Ok(json!(1)),
+        _ => common::method_not_found(),
+    })
+    .await;
+    assert!(Client::new(url.clone()).metrics().is_none());
+    let client = Client::new(url).with_metrics();
+
+    for _ in 0..3 {
+        client.block_number().await.unwrap();
+    }
+    assert!(client.clone().get_work().await.is_err());
+
+    let snapshot = client.metrics().unwrap().snapshot();
+    assert_eq!(snapshot.len(), 2);
+    let block_number = &snapshot["blockNumber"];
+    assert_eq!(block_number.calls, 3);
+    assert_eq!(block_number.errors, 0);
+    let mean = block_number.mean_latency().unwrap();
+    assert!(mean <= block_number.max_latency);
+    let median = block_number.percentile(0.5).unwrap();
+    assert!(median <= block_number.max_latency);
+    assert_eq!(
+        block_number.percentile(1.0).unwrap(),
+        block_number.max_latency
+    );
+    let get_work = &snapshot["getWork"];
+    assert_eq!(get_work.calls, 1);
+    assert_eq!(get_work.errors, 1);
+
+    client.metrics().unwrap().reset();
+    assert!(client.metrics().unwrap().snapshot().is_empty());
+}
@@ -0,0 +1,59 @@
+use nimiq_rpc::mining::*;
+
+#[test]
+fn compact_to_target_genesis() {
+    // Initial Nimiq difficulty 1 target is 2^240.
+    let mut expected = [0u8; 32];
+    expected[1] = 0x01;
+    assert_eq!(compact_to_target(0x1f010000), expected);
+}
+
+#[test]
+fn compact_to_target_mantissa() {
+    let target = compact_to_target(0x1d00ffff);
+    assert_eq!(&target[..6], &[0, 0, 0, 0, 0xff, 0xff]);
+    assert!(target[6..].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn meets_target_compares_big_endian() {
+    let target = compact_to_target(0x1f010000);
+    let mut hash = [0u8; 32];
+    hash[1] = 0x01;
+    assert!(meets_target(&hash, &target));
+    hash[31] = 0x01;
+    assert!(!meets_target(&hash, &target));
+}
+
+#[test]
+fn set_nonce_writes_last_bytes() {
+    let mut header = vec![0u8; HEADER_SIZE];
+    set_nonce(&mut header, 0x01020304);
+    assert_eq!(&header[HEADER_SIZE - 4..], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn get_work_pool_share() {
+    use nimiq_rpc::primitives::GetWork;
+
+    // A header with network target 0x1f010000 (2^240).
+    let mut header = vec![0u8; HEADER_SIZE];
+    header[130..134].copy_from_slice(&0x1f010000u32.to_be_bytes());
+    let work = GetWork {
+        data: hex::encode(&header),
+        suffix: String::new(),
+        // Pool target 2^248, easier than the network target.
+        target: 0x20010000,
+        algorithm: "nimiq-argon2".to_string(),
+    };
+
+    let mut share = [0u8; 32];
+    share[0] = 0x01;
+    assert!(work.is_pool_share(&share));
+    assert!(!work.is_block(&share));
+
+    let mut block = [0u8; 32];
+    block[1] = 0x01;
+    assert!(work.is_pool_share(&block));
+    assert!(work.is_block(&block));
+}
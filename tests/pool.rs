@@ -0,0 +1,87 @@
+#![cfg(feature = "pool")]
+
+mod common;
+
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc, Mutex,
+};
+
+use nimiq_rpc::pool::PoolWorker;
+use nimiq_rpc::{Client, NimiqError};
+use serde_json::json;
+
+#[tokio::test]
+async fn run_submits_shares() {
+    let submitted = Arc::new(Mutex::new(Vec::new()));
+    let recorded = submitted.clone();
+    let url = common::mock_node(move |method, params| match method {
+        // A target of 0x20ffffff is met by almost every hash.
+        "getWork" => Ok(json!({
+            "data": "00".repeat(146),
+            "suffix": "beef",
+            "target": 0x20ffffffu32,
+            "algorithm": "nimiq-argon2",
+        })),
+        "submitBlock" => {
+            recorded
+                .lock()
+                .unwrap()
+                .push(params[0].as_str().unwrap().to_string());
+            Ok(json!(null))
+        }
+        _ => common::method_not_found(),
+    })
+    .await;
+
+    let shares = Arc::new(Mutex::new(Vec::new()));
+    let accepted = shares.clone();
+    let mut worker = PoolWorker::new(Client::new(url))
+        .nonces_per_round(1)
+        .on_share(move |share| accepted.lock().unwrap().push(share.clone()));
+    worker.run(Some(2)).await.unwrap();
+
+    let shares = shares.lock().unwrap();
+    let submitted = submitted.lock().unwrap();
+    assert_eq!(shares.len(), 2);
+    assert_eq!(submitted.len(), 2);
+    // Every round continues with the next nonce.
+    assert_eq!(shares[0].nonce, 0);
+    assert_eq!(shares[1].nonce, 1);
+    assert!(submitted[1].ends_with("00000001beef"));
+}
+
+#[tokio::test]
+async fn run_skips_rejected_shares() {
+    let submitted = AtomicU32::new(0);
+    let url = common::mock_node(move |method, _| match method {
+        "getWork" => Ok(json!({
+            "data": "00".repeat(146),
+            "suffix": "",
+            "target": 0x20ffffffu32,
+            "algorithm": "nimiq-argon2",
+        })),
+        "submitBlock" if submitted.fetch_add(1, Ordering::SeqCst) == 0 => {
+            Err((-32000, "Stale share".to_string()))
+        }
+        "submitBlock" => Ok(json!(null)),
+        _ => common::method_not_found(),
+    })
+    .await;
+    let mut worker = PoolWorker::new(Client::new(url)).nonces_per_round(1);
+    worker.run(Some(1)).await.unwrap();
+}
+
+#[tokio::test]
+async fn run_stops_when_work_cannot_be_fetched() {
+    let url = common::mock_node(|method, _| match method {
+        "getWork" => Err((-32000, "Node is not mining".to_string())),
+        _ => common::method_not_found(),
+    })
+    .await;
+    let mut worker = PoolWorker::new(Client::new(url));
+    let result = tokio::time::timeout(std::time::Duration::from_secs(5), worker.run(None))
+        .await
+        .expect("run must not retry failing work requests forever");
+    assert!(matches!(result, Err(NimiqError::Rpc(_))));
+}
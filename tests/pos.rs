@@ -0,0 +1,84 @@
+#![cfg(feature = "pos")]
+
+use nimiq_rpc::{pos::*, primitives::*};
+
+#[test]
+fn staking_contract() {
+    let json = r#"{
+        "address": "NQ77 0000 0000 0000 0000 0000 0000 0000 0001",
+        "balance": 2500000000000,
+        "type": "staking"
+    }"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.account_type(), AccountType::Staking);
+    assert!(account.is_contract());
+    match account {
+        Account::Staking(contract) => {
+            assert_eq!(contract.address, STAKING_CONTRACT_ADDRESS);
+            assert_eq!(contract.total_stake(), 2500000000000);
+            assert_eq!(contract.active_validators, None);
+        }
+        _ => panic!("Expected the staking contract"),
+    }
+}
+
+#[test]
+fn basic_account_is_not_staking_contract() {
+    let json = r#"{
+        "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "balance": 1200000,
+        "type": "basic"
+    }"#;
+    assert!(serde_json::from_str::<StakingContract>(json).is_err());
+}
+
+#[test]
+fn policy_epochs_and_batches() {
+    let policy = Policy {
+        genesis_block_number: 100,
+        blocks_per_batch: 10,
+        batches_per_epoch: 4,
+    };
+    assert_eq!(policy.blocks_per_epoch(), 40);
+
+    assert_eq!(policy.epoch_at(100), 0);
+    assert_eq!(policy.epoch_at(101), 1);
+    assert_eq!(policy.epoch_at(140), 1);
+    assert_eq!(policy.epoch_at(141), 2);
+    assert_eq!(policy.batch_at(110), 1);
+    assert_eq!(policy.batch_at(111), 2);
+
+    assert!(policy.is_macro_block(100));
+    assert!(policy.is_macro_block(120));
+    assert!(!policy.is_macro_block(125));
+    assert!(!policy.is_macro_block(90));
+    assert!(policy.is_election_block(140));
+    assert!(!policy.is_election_block(120));
+}
+
+#[test]
+fn inherents() {
+    let json = r#"[
+        {
+            "type": 1,
+            "blockNumber": 3456060,
+            "blockTime": 1700000000000,
+            "target": "NQ57 UQJL 5A3H N45M 1FHS 2454 C7L5 BTE6 KEU1",
+            "value": 174591,
+            "data": null,
+            "hash": "9a8ea1e1a0ae0d3e4e2bd9a6b4d1f8b21a1d6e4fb0cb1ef0f6b94d3c2c6c1a5e"
+        },
+        {
+            "type": 0,
+            "blockNumber": 3456060,
+            "target": "NQ57 UQJL 5A3H N45M 1FHS 2454 C7L5 BTE6 KEU1",
+            "value": 0
+        }
+    ]"#;
+    let inherents: Vec<Inherent> = serde_json::from_str(json).unwrap();
+    assert_eq!(inherents.len(), 2);
+    assert_eq!(inherents[0].ty, 1);
+    assert_eq!(inherents[0].value, 174591);
+    assert_eq!(inherents[1].block_time, None);
+    assert_eq!(inherents[1].hash, None);
+}
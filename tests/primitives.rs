@@ -0,0 +1,976 @@
+use nimiq_rpc::primitives::*;
+
+#[test]
+fn transaction_by_block_number_and_index() {
+    let json = r#"{
+        "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "blockHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "blockNumber": 76415,
+        "timestamp": 1528297445,
+        "confirmations": 151281,
+        "transactionIndex": 0,
+        "from": "ad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "value": 2636710000,
+        "fee": 0,
+        "data": null,
+        "flags": 0
+    }"#;
+    let tx: Transaction = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        tx.block_hash,
+        "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
+    );
+    assert_eq!(tx.block_number, 76415);
+}
+
+#[test]
+fn transaction_legacy_blocktxn_hash() {
+    let json = r#"{
+        "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "blocktxnHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "blockNumber": 76415,
+        "timestamp": 1528297445,
+        "confirmations": 151281,
+        "transactionIndex": 0,
+        "from": "ad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "value": 2636710000,
+        "fee": 0,
+        "data": null,
+        "flags": 0
+    }"#;
+    let tx: Transaction = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        tx.block_hash,
+        "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
+    );
+}
+
+#[test]
+fn account_type() {
+    let basic: Account = serde_json::from_str(
+        r#"{
+            "id": "ad25610feb43d75307763d3f010822a757027429",
+            "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "balance": 1200000,
+            "type": 0
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(basic.account_type(), AccountType::Basic);
+    assert!(!basic.is_contract());
+
+    let vesting: Account = serde_json::from_str(
+        r#"{
+            "id": "ad25610feb43d75307763d3f010822a757027429",
+            "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "balance": 52500000000000,
+            "type": 1,
+            "owner": "fd34ab7265a0e48c454ccbf4c9c61dfdf68f9a22",
+            "ownerAddress": "NQ62 YLSA NUK5 L3J8 QHAC RFSC KHGV YPT8 Y6H2",
+            "vestingStart": 1,
+            "vestingStepBlocks": 259200,
+            "vestingStepAmount": 2625000000000,
+            "vestingTotalAmount": 52500000000000
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(vesting.account_type(), AccountType::Vesting);
+    assert!(vesting.is_contract());
+    assert_eq!(
+        vesting.info(),
+        AccountInfo {
+            address: "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+            balance: 52500000000000,
+            account_type: AccountType::Vesting,
+        }
+    );
+}
+
+#[test]
+fn account_type_from_number() {
+    assert_eq!(AccountType::from(2), AccountType::Htlc);
+    // The staking contract has no numeric type.
+    assert_eq!(AccountType::from(3), AccountType::Unknown(3));
+}
+
+#[test]
+fn account_variant_follows_type() {
+    let basic: Account = serde_json::from_str(
+        r#"{
+            "id": "ad25610feb43d75307763d3f010822a757027429",
+            "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "balance": 1200000,
+            "type": 0
+        }"#,
+    )
+    .unwrap();
+    assert!(matches!(basic, Account::Basic(ref account) if account.balance == 1200000));
+
+    let vesting: Account = serde_json::from_str(
+        r#"{
+            "id": "fd34ab7265a0e48c454ccbf4c9c61dfdf68f9a22",
+            "address": "NQ62 YLSA NUK5 L3J8 QHAC RFSC KHGV YPT8 Y6H2",
+            "balance": 52500000000000,
+            "type": 1,
+            "owner": "ad25610feb43d75307763d3f010822a757027429",
+            "ownerAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "vestingStart": 1,
+            "vestingStepBlocks": 259200,
+            "vestingStepAmount": 2625000000000,
+            "vestingTotalAmount": 52500000000000
+        }"#,
+    )
+    .unwrap();
+    let Account::Vesting(vesting) = vesting else {
+        panic!("expected a vesting account, got {:?}", vesting);
+    };
+    assert_eq!(vesting.owner, "ad25610feb43d75307763d3f010822a757027429");
+    assert_eq!(vesting.vesting_step_blocks, 259200);
+    assert_eq!(vesting.vesting_total_amount, 52500000000000);
+
+    let htlc: Account = serde_json::from_str(
+        r#"{
+            "id": "4974636bd6d34d52b7d4a2ee4425dc2be72a2b4e",
+            "address": "NQ15 95S6 6SXN SD6M 5DXL LBP4 89EU 5FKJ LASE",
+            "balance": 1000000,
+            "type": 2,
+            "sender": "ad25610feb43d75307763d3f010822a757027429",
+            "senderAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "recipient": "fd34ab7265a0e48c454ccbf4c9c61dfdf68f9a22",
+            "recipientAddress": "NQ62 YLSA NUK5 L3J8 QHAC RFSC KHGV YPT8 Y6H2",
+            "hashRoot": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+            "hashAlgorithm": 3,
+            "hashCount": 1,
+            "timeout": 1000,
+            "totalAmount": 1000000
+        }"#,
+    )
+    .unwrap();
+    let Account::HTLC(htlc) = htlc else {
+        panic!("expected an HTLC account, got {:?}", htlc);
+    };
+    assert_eq!(htlc.recipient, "fd34ab7265a0e48c454ccbf4c9c61dfdf68f9a22");
+    assert_eq!(htlc.timeout, 1000);
+    assert_eq!(htlc.total_amount, 1000000);
+
+    // A basic account with the fields of a vesting contract is still a basic account.
+    let basic: Account = serde_json::from_str(
+        r#"{
+            "id": "ad25610feb43d75307763d3f010822a757027429",
+            "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "balance": 1200000,
+            "type": 0,
+            "owner": "fd34ab7265a0e48c454ccbf4c9c61dfdf68f9a22",
+            "ownerAddress": "NQ62 YLSA NUK5 L3J8 QHAC RFSC KHGV YPT8 Y6H2",
+            "vestingStart": 1,
+            "vestingStepBlocks": 259200,
+            "vestingStepAmount": 2625000000000,
+            "vestingTotalAmount": 52500000000000
+        }"#,
+    )
+    .unwrap();
+    assert!(matches!(basic, Account::Basic(_)));
+
+    // A vesting contract without its vesting fields is an error, not a basic account.
+    let incomplete = r#"{
+        "id": "ad25610feb43d75307763d3f010822a757027429",
+        "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "balance": 1200000,
+        "type": 1
+    }"#;
+    let error = serde_json::from_str::<Account>(incomplete).unwrap_err();
+    assert!(error.to_string().contains("owner"), "{}", error);
+
+    let unknown = incomplete.replace("\"type\": 1", "\"type\": 7");
+    let error = serde_json::from_str::<Account>(&unknown).unwrap_err();
+    assert!(
+        error.to_string().contains("unknown account type 7"),
+        "{}",
+        error
+    );
+
+    let untyped = incomplete.replace(",\n        \"type\": 1", "");
+    let error = serde_json::from_str::<Account>(&untyped).unwrap_err();
+    assert!(error.to_string().contains("type"), "{}", error);
+}
+
+#[test]
+fn transaction_sequence_iter() {
+    let sequence: TransactionSequence = serde_json::from_str(
+        r#"["465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"]"#,
+    )
+    .unwrap();
+    assert_eq!(sequence.len(), 1);
+    assert!(!sequence.is_empty());
+    let hashes: Vec<&str> = (&sequence).into_iter().map(|tx| tx.hash()).collect();
+    assert_eq!(
+        hashes,
+        ["465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"]
+    );
+    assert!(matches!(sequence.get(0), Some(TxOrHash::Hash(_))));
+    assert!(sequence.get(1).is_none());
+}
+
+#[test]
+fn outgoing_transaction_content_hash() {
+    let tx = OutgoingTransaction {
+        from: "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+        to: "824aa01033c89595479bab9d8deb4fc9f90e1ebf".to_string(),
+        value: 2636710000,
+        fee: 0,
+        data: None,
+        validity_start_height: None,
+        network_id: None,
+    };
+    // The content is serialized like a basic transaction by the nodes: data length (u16) and data,
+    // sender and recipient each followed by its account type (u8), value and fee (u64), validity start
+    // height (u32), network id and flags (u8), all big-endian, and hashed with Blake2b-256:
+    // 0000 ad25..7429 00 824a..1ebf 00 000000009d290070 0000000000000000 00012a7d 2a 00
+    let hash = tx.content_hash(76413, 42).unwrap();
+    assert_eq!(
+        hash,
+        "85788d51aef22c062beb7ce783cdb03b24d8fd66c1ad6c76e2f1c06765d4c1d1"
+    );
+    // 0004 72656e74 ad25..7429 00 824a..1ebf 00 00000000000186a0 000000000000008a 00012a7d 2a 00
+    let rent = OutgoingTransaction {
+        value: 100000,
+        fee: 138,
+        data: Some(hex::encode("rent")),
+        ..tx.clone()
+    };
+    assert_eq!(
+        rent.content_hash(76413, 42).unwrap(),
+        "7c1d3e94b3ebab691e508dc1b20964e3158686ccca60e66e9b389be4cd784f73"
+    );
+
+    // The same transaction with the sender in hex format.
+    let hex_sender = OutgoingTransaction {
+        from: "ad25610feb43d75307763d3f010822a757027429".to_string(),
+        ..tx.clone()
+    };
+    assert_ne!(tx, hex_sender);
+    assert_eq!(hex_sender.content_hash(76413, 42).unwrap(), hash);
+
+    assert_ne!(tx.content_hash(76414, 42).unwrap(), hash);
+    assert_ne!(tx.content_hash(76413, 1).unwrap(), hash);
+    let with_data = OutgoingTransaction {
+        data: Some("6869".to_string()),
+        ..tx.clone()
+    };
+    assert_ne!(with_data.content_hash(76413, 42).unwrap(), hash);
+
+    let bad_checksum = OutgoingTransaction {
+        from: "NQ16 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+        ..tx
+    };
+    assert_eq!(bad_checksum.content_hash(76413, 42), None);
+}
+
+#[test]
+fn genesis_block() {
+    let json = r#"{
+        "number": 1,
+        "hash": "264aaf8a4f9828a76c550635da078eb466306a189fcc03710bee9f649c869d12",
+        "pow": "000000000000000000000000000000000000000000000000000000000000000",
+        "parentHash": "0000000000000000000000000000000000000000000000000000000000000000",
+        "nonce": 0,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "0000000000000000000000000000000000000000",
+        "minerAddress": "NQ07 0000 0000 0000 0000 0000 0000 0000 0000",
+        "difficulty": "1",
+        "extraData": "",
+        "size": 146,
+        "timestamp": 1523727000,
+        "transactions": []
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert_eq!(block.number, 1);
+    assert!(block.transactions.is_empty());
+}
+
+#[test]
+fn genesis_block_without_parent_hash_and_pow() {
+    let json = r#"{
+        "number": 1,
+        "hash": "264aaf8a4f9828a76c550635da078eb466306a189fcc03710bee9f649c869d12",
+        "nonce": 0,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "0000000000000000000000000000000000000000",
+        "minerAddress": "NQ07 0000 0000 0000 0000 0000 0000 0000 0000",
+        "difficulty": "1",
+        "size": 146,
+        "timestamp": 1523727000,
+        "transactions": []
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert_eq!(block.parent_hash, "");
+    assert_eq!(block.pow, "");
+    assert_eq!(block.extra_data, "");
+}
+
+#[test]
+fn coin_formatting() {
+    assert_eq!(Coin(123_456_789).format_with_unit(), "1,234.56789 NIM");
+    assert_eq!(Coin(123_456_789).format_luna(), "123456789");
+    assert_eq!(Coin(0).format_with_unit(), "0.00000 NIM");
+    assert_eq!(Coin(1).format_with_unit(), "0.00001 NIM");
+    assert_eq!(
+        Coin(100_000_000_000_000).format_with_unit(),
+        "1,000,000,000.00000 NIM"
+    );
+    assert_eq!(
+        Coin(u64::MAX).format_with_unit(),
+        "184,467,440,737,095.51615 NIM"
+    );
+}
+
+#[test]
+fn format_bytes_units() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.0 KiB");
+    assert_eq!(format_bytes(1_258_291), "1.2 MiB");
+    // Just under 1 MiB rounds up to the next unit.
+    assert_eq!(format_bytes(1_048_575), "1.0 MiB");
+    assert_eq!(format_bytes(1_048_524), "1023.9 KiB");
+    assert_eq!(format_bytes(5 << 30), "5.0 GiB");
+    assert_eq!(format_bytes(u64::MAX), "16.0 EiB");
+}
+
+#[test]
+fn peer_display() {
+    let json = r#"{
+        "id": "b99034c552e9c0fd34eb95c1cdf17f5e",
+        "address": "wss://seed1.nimiq-testnet.com:8080/b99034c552e9c0fd34eb95c1cdf17f5e",
+        "addressState": 2,
+        "connectionState": 5,
+        "version": 2,
+        "timeOffset": -188,
+        "headHash": "59da8ba57c1f0ffd444201ca2d9f48cef7e661262781be7937bb6ef0bdbe0e4d",
+        "latency": 532,
+        "rx": 2122,
+        "tx": 1265
+    }"#;
+    let peer: PeerList = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        peer.to_string(),
+        "wss://seed1.nimiq-testnet.com:8080/b99034c552e9c0fd34eb95c1cdf17f5e address: established, connection: established, latency: 532 ms"
+    );
+    assert_eq!(peer.rx_human().as_deref(), Some("2.1 KiB"));
+    assert_eq!(peer.tx_human().as_deref(), Some("1.2 KiB"));
+
+    let state = PeerState {
+        id: "b99034c552e9c0fd34eb95c1cdf17f5e".to_string(),
+        address: "wss://seed1.nimiq-testnet.com:8080/b99034c552e9c0fd34eb95c1cdf17f5e".to_string(),
+        address_state: 9,
+    };
+    assert_eq!(
+        state.to_string(),
+        "wss://seed1.nimiq-testnet.com:8080/b99034c552e9c0fd34eb95c1cdf17f5e address: unknown (9)"
+    );
+}
+
+#[test]
+fn hash_parsing() {
+    let hash: Hash = "465A63B73AA0B9B54B777BE9A585EA00B367A17898AD520E1F22CB2C986FF554"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        hash.to_string(),
+        "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+    );
+    assert_eq!(
+        serde_json::from_str::<Hash>(
+            r#""465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554""#
+        )
+        .unwrap(),
+        hash
+    );
+    assert_eq!("465a63".parse::<Hash>(), Err(HashError::InvalidLength(6)));
+    assert_eq!(
+        "z65a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554".parse::<Hash>(),
+        Err(HashError::InvalidHex)
+    );
+}
+
+#[test]
+fn block_summary() {
+    let json = r#"{
+        "number": 882418,
+        "hash": "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6",
+        "pow": "00000000000d6b1bc3a5d8c05c5b7fb4d6e2dbbfd2fa52a4a52ec6bd6a58e5e8",
+        "parentHash": "e2ff2a3b3d3cd9bc6ef72cf3d0ba59f3a0a7e0ae5db0c1bd8bda3bba0a1c96f9",
+        "nonce": 1146518856,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "fbbb94d1fbd1d3d3a7ed2a1d1c47a0b0e2f5e8c4",
+        "minerAddress": "NQ36 YEXR 9LFT SF9P 79YD 58EH 8HX0 N3HF BT64",
+        "difficulty": "224356.25830258",
+        "extraData": "",
+        "size": 576,
+        "timestamp": 1571844434,
+        "transactions": [
+            "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+            "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
+        ]
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    let summary = BlockSummary::from(block);
+    assert_eq!(summary.number, 882418);
+    assert_eq!(summary.transaction_count, 2);
+}
+
+#[test]
+fn block_to_header() {
+    let json = r#"{
+        "number": 882418,
+        "hash": "a9284b441b56e93de62f557414cc9b850bad2bd30cf84b013cfe2ef6e11b6da6",
+        "parentHash": "e2ff2a3b3d3cd9bc6ef72cf3d0ba59f3a0a7e0ae5db0c1bd8bda3bba0a1c96f9",
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "difficulty": "1",
+        "size": 576,
+        "timestamp": 1571844434
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    let header = block.to_header();
+    assert_eq!(header.height, 882418);
+    assert_eq!(header.prev_hash, block.parent_hash);
+    assert_eq!(header.accounts_hash, block.accounts_hash);
+    assert_eq!(header.n_bits, 0x1f010000);
+    assert_eq!(header.difficulty(), 1.0);
+    assert_eq!(header.interlink_hash, "");
+
+    // Albatross blocks have no difficulty.
+    let json = json.replace("\"difficulty\": \"1\",", "");
+    let block: Block = serde_json::from_str(&json).unwrap();
+    assert_eq!(block.to_header().n_bits, 0);
+}
+
+#[test]
+fn block_with_mixed_transactions() {
+    let json = r#"{
+        "number": 76415,
+        "hash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "pow": "00000000000d6b1bc3a5d8c05c5b7fb4d6e2dbbfd2fa52a4a52ec6bd6a58e5e8",
+        "parentHash": "e2ff2a3b3d3cd9bc6ef72cf3d0ba59f3a0a7e0ae5db0c1bd8bda3bba0a1c96f9",
+        "nonce": 42,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "ad25610feb43d75307763d3f010822a757027429",
+        "minerAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "difficulty": "1",
+        "extraData": "",
+        "size": 576,
+        "timestamp": 1528297445,
+        "transactions": [
+            "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572e",
+            {
+                "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+                "blockHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+                "blockNumber": 76415,
+                "timestamp": 1528297445,
+                "confirmations": 1,
+                "transactionIndex": 1,
+                "from": "ad25610feb43d75307763d3f010822a757027429",
+                "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+                "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+                "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+                "value": 2636710000,
+                "fee": 0,
+                "flags": 0
+            }
+        ]
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert!(matches!(block.transactions, TransactionSequence::Mixed(_)));
+    assert_eq!(block.transactions.len(), 2);
+    let hashes: Vec<_> = block.transactions.iter().map(|tx| tx.hash()).collect();
+    assert_eq!(
+        hashes,
+        [
+            "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572e",
+            "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+        ]
+    );
+    assert!(matches!(block.transactions.get(1), Some(TxOrHash::Full(_))));
+}
+
+#[test]
+fn hex_fields_with_and_without_prefix() {
+    let unprefixed = r#"{
+        "transactionHash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "transactionIndex": 0,
+        "blockNumber": 76415,
+        "blockHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "confirmations": 151281,
+        "timestamp": 1528297445
+    }"#;
+    let prefixed = r#"{
+        "transactionHash": "0x465A63B73AA0B9B54B777BE9A585EA00B367A17898AD520E1F22CB2C986FF554",
+        "transactionIndex": 0,
+        "blockNumber": 76415,
+        "blockHash": "0xdfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "confirmations": 151281,
+        "timestamp": 1528297445
+    }"#;
+    for json in [unprefixed, prefixed] {
+        let receipt: TransactionReceipt = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            receipt.transaction_hash,
+            "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554"
+        );
+        assert_eq!(
+            receipt.block_hash,
+            "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f"
+        );
+    }
+
+    let json = r#"{
+        "hash": "0x465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "blockHash": null,
+        "from": "0xad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "value": 2636710000,
+        "fee": 0,
+        "data": "0xCAFE",
+        "flags": 0
+    }"#;
+    let tx: TransactionDetails = serde_json::from_str(json).unwrap();
+    assert_eq!(tx.from, "ad25610feb43d75307763d3f010822a757027429");
+    assert_eq!(tx.block_hash, None);
+    assert_eq!(tx.data.as_deref(), Some("cafe"));
+    assert_eq!(tx.proof, None);
+}
+
+#[test]
+fn transaction_flags() {
+    let flags = TransactionFlags::from(0b01);
+    assert!(flags.is_contract_creation());
+    assert!(!flags.is_signaling());
+    assert_eq!(flags.bits(), 1);
+    assert!(TransactionFlags(0b11).contains(TransactionFlags::SIGNALING));
+    assert!(!TransactionFlags::default().is_contract_creation());
+}
+
+#[test]
+fn htlc_account() {
+    let json = r#"{
+        "id": "4974636bd6d34d52b7d4a2ee4425dc2be72a2b4e",
+        "address": "NQ46 95Q6 6SXN SD6M 5DXL LBP4 89EU 5FKJ LASE",
+        "balance": 1000000,
+        "type": 2,
+        "sender": "d62d519b3478c63bdd729cf2ccb863178060c64a",
+        "senderAddress": "NQ53 SQNM 36RL F333 PPBJ KKRC RE33 2X06 1HJA",
+        "recipient": "f5ad55071730d3b9f05989481eefbda7324a44f8",
+        "recipientAddress": "NQ41 XNNM A1QP 63AT KU2R H54H 3TXV LUR4 LH7Q",
+        "hashRoot": "df331b3c8f8a889703092ea05503779058b7f44e71bc57176378adde424ce922",
+        "hashAlgorithm": 3,
+        "hashCount": 1,
+        "timeout": 1105605,
+        "totalAmount": 1000000
+    }"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    let Account::HTLC(htlc) = account else {
+        panic!("Expected an HTLC account, got {:?}", account);
+    };
+    assert_eq!(htlc.hash_algorithm_enum(), HashAlgorithm::Sha256);
+    assert_eq!(HashAlgorithm::from(9), HashAlgorithm::Unknown(9));
+}
+
+fn htlc(hash_algorithm: u8, hash_count: u8, hash_root: &str) -> HTLCAccount {
+    HTLCAccount {
+        id: "4974636bd6d34d52b7d4a2ee4425dc2be72a2b4e".to_string(),
+        address: "NQ46 95Q6 6SXN SD6M 5DXL LBP4 89EU 5FKJ LASE".to_string(),
+        balance: 1000000,
+        r#type: 2,
+        sender: "d62d519b3478c63bdd729cf2ccb863178060c64a".to_string(),
+        sender_address: "NQ53 SQNM 36RL F333 PPBJ KKRC RE33 2X06 1HJA".to_string(),
+        recipient: "f5ad55071730d3b9f05989481eefbda7324a44f8".to_string(),
+        recipient_address: "NQ41 XNNM A1QP 63AT KU2R H54H 3TXV LUR4 LH7Q".to_string(),
+        hash_root: hash_root.to_string(),
+        hash_algorithm,
+        hash_count,
+        timeout: 1000,
+        total_amount: 1000000,
+    }
+}
+
+#[test]
+fn htlc_can_resolve_with() {
+    let blake2b = htlc(
+        1,
+        2,
+        "142e2a516ae52877e0efaa3668c9dd0f5dded9a57e973fe909cbabc1bc85ee2d",
+    );
+    assert!(blake2b.can_resolve_with(b"nimiq", 999));
+    assert!(blake2b.can_resolve_with(b"nimiq", 1000));
+    assert!(!blake2b.can_resolve_with(b"nimiq", 1001));
+    assert!(!blake2b.can_resolve_with(b"nimiq!", 999));
+
+    let sha256 = htlc(
+        3,
+        1,
+        "D429C562A2F3568A0F3A56FA0EE9820BA97E16CCFF25E4277CE42F05F50354D5",
+    );
+    assert!(sha256.can_resolve_with(b"nimiq", 0));
+
+    let unknown = htlc(
+        9,
+        1,
+        "d429c562a2f3568a0f3a56fa0ee9820ba97e16ccff25e4277ce42f05f50354d5",
+    );
+    assert!(!unknown.can_resolve_with(b"nimiq", 0));
+}
+
+#[test]
+fn amounts_as_numbers_or_strings() {
+    for (balance, expected) in [
+        ("1200000", 1200000),
+        ("\"1200000\"", 1200000),
+        ("1.2e6", 1200000),
+        ("\"18446744073709551615\"", u64::MAX),
+    ] {
+        let json = format!(
+            r#"{{
+                "id": "ad25610feb43d75307763d3f010822a757027429",
+                "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+                "balance": {},
+                "type": 0
+            }}"#,
+            balance
+        );
+        let account: Account = serde_json::from_str(&json).unwrap();
+        assert_eq!(account.info().balance, expected, "{}", balance);
+    }
+
+    let json = r#"{
+        "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "from": "ad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "value": "2636710000",
+        "fee": 138,
+        "flags": 0
+    }"#;
+    let tx: TransactionDetails = serde_json::from_str(json).unwrap();
+    assert_eq!((tx.value, tx.fee), (2636710000, 138));
+
+    for invalid in ["-1", "\"12abc\"", "1.5"] {
+        let json = format!(
+            r#"{{"from": "a", "to": "b", "value": {}, "fee": 0, "data": null}}"#,
+            invalid
+        );
+        assert!(serde_json::from_str::<OutgoingTransaction>(&json).is_err());
+    }
+}
+
+#[test]
+fn coin_arithmetic() {
+    assert_eq!(Coin(1).checked_add(Coin(2)), Some(Coin(3)));
+    assert_eq!(Coin(u64::MAX).checked_add(Coin(1)), None);
+    assert_eq!(Coin(3).checked_sub(Coin(2)), Some(Coin(1)));
+    assert_eq!(Coin(2).checked_sub(Coin(3)), None);
+    assert_eq!(Coin::try_sum([Coin(1), Coin(2), Coin(3)]), Some(Coin(6)));
+    assert_eq!(Coin::try_sum(Vec::new()), Some(Coin(0)));
+    assert_eq!(Coin::try_sum([Coin(u64::MAX), Coin(1)]), None);
+}
+
+#[test]
+fn transaction_without_flags() {
+    let json = r#"{
+        "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "blockHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "blockNumber": 76415,
+        "timestamp": 1528297445,
+        "confirmations": 151281,
+        "from": "ad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "fromType": 0,
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "toType": 0,
+        "value": 2636710000,
+        "fee": 0,
+        "validityStartHeight": 76413,
+        "networkId": 42
+    }"#;
+    let tx: Transaction = serde_json::from_str(json).unwrap();
+    assert_eq!(tx.flags, 0);
+    let details: TransactionDetails = serde_json::from_str(json).unwrap();
+    assert_eq!(details.flags, 0);
+    let details: TransactionDetails2 = serde_json::from_str(json).unwrap();
+    assert_eq!(details.flags, 0);
+}
+
+#[test]
+fn block_without_transactions() {
+    let json = r#"{
+        "number": 76415,
+        "hash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "pow": "0000000000000000000000000000000000000000000000000000000000000000",
+        "parentHash": "0000000000000000000000000000000000000000000000000000000000000000",
+        "nonce": 42,
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "accountsHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "miner": "ad25610feb43d75307763d3f010822a757027429",
+        "minerAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "difficulty": "1",
+        "extraData": "",
+        "size": 146,
+        "timestamp": 1528297445
+    }"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert!(block.transactions.is_empty());
+    assert_eq!(block.kind(), BlockKind::ProofOfWork);
+}
+
+#[test]
+fn work_without_algorithm() {
+    let json = r#"{
+        "data": "0001",
+        "suffix": "beef",
+        "target": 503382015
+    }"#;
+    let work: GetWork = serde_json::from_str(json).unwrap();
+    assert_eq!(work.algorithm, "");
+}
+
+#[test]
+fn network_ids() {
+    assert_eq!(Network::from(42), Network::Main);
+    assert_eq!(Network::from(1), Network::Test);
+    assert_eq!(u8::from(Network::Unknown(7)), 7);
+    assert_eq!(serde_json::to_string(&Network::Main).unwrap(), "42");
+    let network: Network = serde_json::from_str("2").unwrap();
+    assert_eq!(network.to_string(), "dev");
+}
+
+#[test]
+fn wallet_address() {
+    let wallet: Wallet = serde_json::from_str(
+        r#"{
+            "id": "ad25610feb43d75307763d3f010822a757027429",
+            "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "publicKey": "4f6d35cc47b77bf696b6cce72217e52edff972855bd17396b004a8453b020747"
+        }"#,
+    )
+    .unwrap();
+    let address = Address::try_from(&wallet).unwrap();
+    assert_eq!(address.to_hex(), "ad25610feb43d75307763d3f010822a757027429");
+    assert_eq!(wallet.address_typed(), Ok(address));
+
+    let broken = Wallet {
+        address: "NQ15".to_string(),
+        ..wallet
+    };
+    assert!(matches!(
+        broken.address_typed(),
+        Err(AddressError::InvalidLength(_))
+    ));
+}
+
+#[test]
+fn address_formats_compare_equal() {
+    let friendly: Address = "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19"
+        .parse()
+        .unwrap();
+    let compact: Address = "nq15mljn23yb8fbm61tn7lyg2212lvbg4v19".parse().unwrap();
+    let hex: Address = "AD25610FEB43D75307763D3F010822A757027429".parse().unwrap();
+    assert_eq!(friendly, compact);
+    assert_eq!(friendly, hex);
+    let unique: std::collections::HashSet<_> = [friendly, compact, hex].into_iter().collect();
+    assert_eq!(unique.len(), 1);
+
+    assert_eq!(
+        hex.to_string(),
+        "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19"
+    );
+    assert_eq!(
+        friendly.to_hex(),
+        "ad25610feb43d75307763d3f010822a757027429"
+    );
+    let genesis: Address = "0000000000000000000000000000000000000000".parse().unwrap();
+    assert_eq!(
+        genesis.to_string(),
+        "NQ07 0000 0000 0000 0000 0000 0000 0000 0000"
+    );
+
+    assert_eq!(
+        "NQ16 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".parse::<Address>(),
+        Err(AddressError::InvalidChecksum)
+    );
+    assert_eq!(
+        "NQ15 MLJN".parse::<Address>(),
+        Err(AddressError::InvalidLength(8))
+    );
+    assert_eq!(
+        "DE15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".parse::<Address>(),
+        Err(AddressError::InvalidCountryCode)
+    );
+}
+
+#[test]
+fn albatross_blocks() {
+    let micro = r#"{
+        "hash": "aa9d0d29b3dcf0de05be58b3d8ec1f7c6f1e4e5b8b2ee9bde6a1a7d9bfc1a6a2",
+        "size": 372,
+        "batch": 1,
+        "epoch": 1,
+        "version": 1,
+        "number": 42,
+        "timestamp": 1692352800000,
+        "parentHash": "3d1c1e3dd3a8e1e35f0a2ea0c9b9f9d4c2b8e9bb0c7d5a0ce7e4a3ee6c2f9c1d",
+        "seed": "8c9b4d01ac9e8f59a1a8b0f1e76d3b1c",
+        "extraData": "",
+        "stateHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "historyHash": "0b2f1c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+        "type": "micro",
+        "producer": {
+            "slotNumber": 203,
+            "validator": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+            "publicKey": "9d8b2a7b2f5c1e8e4c7a0d4b7b9f2c1d3e5f60718293a4b5c6d7e8f90a1b2c3d"
+        },
+        "network": "MainAlbatross",
+        "transactions": []
+    }"#;
+    let block: Block = serde_json::from_str(micro).unwrap();
+    assert_eq!(block.number, 42);
+    assert_eq!(block.kind(), BlockKind::Micro);
+    assert_eq!(block.timestamp, 1692352800000);
+    assert_eq!(block.pow, "");
+    assert_eq!(block.nonce, 0);
+    assert_eq!(block.miner_address, "");
+    assert_eq!(
+        block.accounts_hash,
+        "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c"
+    );
+    assert_eq!(
+        block.history_hash,
+        "0b2f1c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9"
+    );
+
+    // Macro blocks carry no transactions.
+    let r#macro = r#"{
+        "hash": "bb9d0d29b3dcf0de05be58b3d8ec1f7c6f1e4e5b8b2ee9bde6a1a7d9bfc1a6a2",
+        "size": 1024,
+        "batch": 1,
+        "epoch": 1,
+        "version": 1,
+        "number": 60,
+        "timestamp": 1692352818000,
+        "parentHash": "aa9d0d29b3dcf0de05be58b3d8ec1f7c6f1e4e5b8b2ee9bde6a1a7d9bfc1a6a2",
+        "seed": "8c9b4d01ac9e8f59a1a8b0f1e76d3b1c",
+        "extraData": "",
+        "stateHash": "1fefd44f1fa97185fda21e957545c97dc7643fa7e4efdd86e0aa4244d1e0bc5c",
+        "bodyHash": "7cda9a7fdf06655905ae5dbd9c535451471b078fa6f3df0e287e5b0fb47a573a",
+        "historyHash": "0b2f1c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+        "type": "macro",
+        "isElectionBlock": false,
+        "parentElectionHash": "0000000000000000000000000000000000000000000000000000000000000000"
+    }"#;
+    let block: Block = serde_json::from_str(r#macro).unwrap();
+    assert_eq!(block.number, 60);
+    assert_eq!(block.kind(), BlockKind::MacroCheckpoint);
+    assert!(block.transactions.is_empty());
+
+    let election = r#macro.replace(r#""isElectionBlock": false"#, r#""isElectionBlock": true"#);
+    let block: Block = serde_json::from_str(&election).unwrap();
+    assert_eq!(block.kind(), BlockKind::MacroElection);
+}
+
+#[test]
+fn coin_accessors() {
+    let transaction = OutgoingTransaction {
+        from: "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+        to: "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK".to_string(),
+        value: 2636710000,
+        fee: 138,
+        data: None,
+        validity_start_height: None,
+        network_id: None,
+    };
+    assert_eq!(transaction.value_coin(), Coin(2636710000));
+    assert_eq!(transaction.fee_coin(), Coin(138));
+
+    let json = r#"{
+        "id": "ad25610feb43d75307763d3f010822a757027429",
+        "address": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "balance": 1200000,
+        "type": 0
+    }"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.balance_coin(), Coin(1200000));
+    assert_eq!(account.info().balance_coin(), Coin(1200000));
+}
+
+#[test]
+fn outgoing_transaction_display() {
+    let mut transaction = OutgoingTransaction {
+        from: "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19".to_string(),
+        to: "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK".to_string(),
+        value: 123456789,
+        fee: 138,
+        data: None,
+        validity_start_height: None,
+        network_id: None,
+    };
+    assert_eq!(
+        transaction.to_string(),
+        "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19 → NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK: 1,234.56789 NIM (fee 0.00138 NIM)"
+    );
+
+    transaction.data = Some(hex::encode("rent"));
+    assert!(transaction.to_string().ends_with(", memo: \"rent\""));
+    transaction.data = Some("cafe00".to_string());
+    assert!(transaction.to_string().ends_with(", data: cafe00"));
+}
+
+#[test]
+fn transaction_with_signature_proof() {
+    // Public key, empty Merkle path and signature of a single-signature wallet.
+    let proof = format!("{}00{}", "ab".repeat(32), "cd".repeat(64));
+    let json = format!(
+        r#"{{
+        "hash": "465a63b73aa0b9b54b777be9a585ea00b367a17898ad520e1f22cb2c986ff554",
+        "blockHash": "dfe7d166f2c86bd10fa4b1f29cd06c13228f893167ce9826137c85758645572f",
+        "blockNumber": 76415,
+        "timestamp": 1528297445,
+        "confirmations": 151281,
+        "transactionIndex": 0,
+        "from": "ad25610feb43d75307763d3f010822a757027429",
+        "fromAddress": "NQ15 MLJN 23YB 8FBM 61TN 7LYG 2212 LVBG 4V19",
+        "to": "824aa01033c89595479bab9d8deb4fc9f90e1ebf",
+        "toAddress": "NQ29 8H5A 0445 R2AR AHUT NEEQ TSUF R7YG U7MK",
+        "value": 2636710000,
+        "fee": 0,
+        "data": null,
+        "proof": "0x{}",
+        "flags": 0
+    }}"#,
+        proof
+    );
+    let details: TransactionDetails = serde_json::from_str(&json).unwrap();
+    assert_eq!(details.proof.as_deref(), Some(proof.as_str()));
+    let bytes = details.signature_proof_bytes().unwrap();
+    assert_eq!(bytes.len(), 97);
+    assert_eq!(bytes[32], 0);
+
+    let details = TransactionDetails {
+        proof: None,
+        ..details
+    };
+    assert_eq!(details.signature_proof_bytes(), None);
+}
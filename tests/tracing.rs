@@ -0,0 +1,69 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use nimiq_rpc::{jsonrpsee::rpc_params, Client};
+use serde_json::json;
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Records the fields of all events emitted by this crate.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("nimiq_rpc")
+    }
+
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[tokio::test]
+async fn sensitive_params_are_redacted() {
+    let url = common::mock_node(|_, _| Ok(json!(true))).await;
+    let client = Client::new(url);
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let _: bool = client
+        .call("unlockAccount", rpc_params!["NQ15 MLJN", "hunter2", 0])
+        .await
+        .unwrap();
+    let _: bool = client
+        .call("getBalance", rpc_params!["NQ15 MLJN"])
+        .await
+        .unwrap();
+
+    let logged = recorder.0.lock().unwrap().join(" ");
+    assert!(!logged.contains("hunter2"));
+    assert!(logged.contains(r#"method="unlockAccount" params="[redacted]""#));
+    assert!(logged.contains(r#"method="getBalance" params="[\"NQ15 MLJN\"]""#));
+}